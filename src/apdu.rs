@@ -0,0 +1,99 @@
+//! APDUs that send a transaction to the Casper Ledger app for signing.
+//!
+//! The app receives the transaction as a series of APDUs: the first one carries the BIP32 derivation path
+//! of the signing key, the following ones the serialized deploy in chunks of at most [`CHUNK_SIZE`] bytes.
+
+use serde::{Deserialize, Serialize};
+
+/// Instruction class of the Casper app.
+pub const CLA: u8 = 0x11;
+/// Instruction signing a transaction.
+pub const INS_SIGN: u8 = 0x02;
+
+/// First APDU of the transaction, carrying the derivation path.
+pub const P1_INIT: u8 = 0x00;
+/// APDU carrying a chunk of the transaction, followed by more chunks.
+pub const P1_ADD: u8 = 0x01;
+/// APDU carrying the last chunk of the transaction.
+pub const P1_LAST: u8 = 0x02;
+
+/// Maximum size of the data of a single APDU, in bytes.
+pub const CHUNK_SIZE: usize = 250;
+
+// Hardened derivation path components have the highest bit set.
+const HARDENED: u32 = 0x8000_0000;
+
+/// Derivation path of the signing key, `m/44'/506'/0'/0/0`.
+pub const DERIVATION_PATH: [u32; 5] = [44 | HARDENED, 506 | HARDENED, HARDENED, 0, 0];
+
+/// Single command APDU, with its data hex-encoded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Apdu {
+    pub cla: u8,
+    pub ins: u8,
+    pub p1: u8,
+    pub p2: u8,
+    pub data: String,
+}
+
+impl Apdu {
+    fn sign(p1: u8, data: &[u8]) -> Self {
+        Apdu {
+            cla: CLA,
+            ins: INS_SIGN,
+            p1,
+            p2: 0,
+            data: hex::encode(data),
+        }
+    }
+
+    /// Whole APDU, hex-encoded: the header, the length of the data and the data itself.
+    pub fn to_hex(&self) -> String {
+        format!(
+            "{:02x}{:02x}{:02x}{:02x}{:02x}{}",
+            self.cla,
+            self.ins,
+            self.p1,
+            self.p2,
+            self.data.len() / 2,
+            self.data
+        )
+    }
+}
+
+/// Returns the APDUs that send the serialized transaction to the app for signing with the key
+/// at [`DERIVATION_PATH`].
+pub fn sign_apdus(blob: &[u8]) -> Vec<Apdu> {
+    let path: Vec<u8> = DERIVATION_PATH
+        .iter()
+        .flat_map(|component| component.to_le_bytes())
+        .collect();
+    let mut apdus = vec![Apdu::sign(P1_INIT, &path)];
+    let chunks: Vec<&[u8]> = blob.chunks(CHUNK_SIZE).collect();
+    for (idx, chunk) in chunks.iter().enumerate() {
+        let p1 = if idx + 1 == chunks.len() {
+            P1_LAST
+        } else {
+            P1_ADD
+        };
+        apdus.push(Apdu::sign(p1, chunk));
+    }
+    apdus
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sign_apdus, CHUNK_SIZE, P1_ADD, P1_INIT, P1_LAST};
+
+    #[test]
+    fn path_then_chunks() {
+        let blob = vec![0xab; CHUNK_SIZE + 1];
+        let apdus = sign_apdus(&blob);
+        let p1s: Vec<u8> = apdus.iter().map(|apdu| apdu.p1).collect();
+        assert_eq!(vec![P1_INIT, P1_ADD, P1_LAST], p1s);
+        // 44', 506', 0', 0, 0 as little-endian u32s.
+        let path = "2c000080fa010080000000800000000000000000";
+        assert_eq!(format!("1102000014{}", path), apdus[0].to_hex());
+        assert_eq!("1102020001ab", apdus[2].to_hex());
+    }
+}
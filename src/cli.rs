@@ -0,0 +1,205 @@
+mod apdus;
+mod generate;
+mod render;
+mod report;
+mod snapshots;
+mod verify;
+
+use std::error::Error;
+
+use casper_deploy_generator::{
+    ledger::{self, DeviceProfile, LimitedLedgerConfig, ZondaxRepr},
+    parser::{AmountFormat, ArgsLimits, ParserConfig},
+    sample::Sample,
+    test_data::{SampleFamily, SampleFilter},
+    Network, TestRng,
+};
+use casper_node::types::Deploy;
+use clap::{Args, Parser, Subcommand};
+use glob::Pattern;
+
+/// Test vectors for Ledger hardware integration with CasperNetwork.
+#[derive(Parser)]
+#[clap(name = "casper-deploy-generator")]
+pub(crate) struct Cli {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Writes the APDUs sending every test vector to the app for signing, as replayable scripts.
+    Apdus(apdus::ApdusArgs),
+    /// Generates the test vectors from all the sample families.
+    Generate(generate::GenerateArgs),
+    /// Prints the Ledger pages (regular and expert) of a single deploy.
+    Render(render::RenderArgs),
+    /// Writes a static HTML report for browsing the test vectors.
+    Report(report::ReportArgs),
+    /// Draws every screen of the test vectors, as displayed on the device, to separate files.
+    Snapshots(snapshots::SnapshotsArgs),
+    /// Checks an existing test vectors file against a fresh run of the generator.
+    Verify(verify::VerifyArgs),
+}
+
+impl Cli {
+    pub(crate) fn run(self) -> Result<(), Box<dyn Error>> {
+        match self.command {
+            Command::Apdus(args) => apdus::run(args),
+            Command::Generate(args) => generate::run(args),
+            Command::Render(args) => render::run(args),
+            Command::Report(args) => report::run(args),
+            Command::Snapshots(args) => snapshots::run(args),
+            Command::Verify(args) => verify::run(args),
+        }
+    }
+}
+
+/// Options shared by all the commands that run the samples generator.
+#[derive(Args)]
+pub(crate) struct GeneratorOpts {
+    /// Hex-encoded, 16 bytes long seed for the PRNG.
+    /// The same seed always produces the same test vectors. Random when not set.
+    #[clap(long, env = "CL_TEST_SEED", parse(try_from_str = parse_seed))]
+    seed: Option<[u8; 16]>,
+    /// Maximum number of elements (numbered entries of the output) a transaction can be displayed with,
+    /// however many screens each of them spans.
+    #[clap(long, default_value = "15")]
+    element_limit: u8,
+    /// Ledger device model the pages are laid out for: nanos, nanox, nanosp, stax or flex.
+    #[clap(long, default_value = "nanos")]
+    device: DeviceProfile,
+    /// Format of the amounts: cspr, motes or both (CSPR in regular mode, motes in expert mode).
+    #[clap(long, default_value = "motes")]
+    amount_format: AmountFormat,
+    /// Lists all the runtime arguments (`arg-n-name`/`arg-n-val`) in expert mode, in addition to their hash.
+    /// Arguments exceeding any of the `--max-arg*` limits are not listed.
+    #[clap(long)]
+    list_args: bool,
+    /// Maximum number of listed arguments, at most 100 (longer labels don't fit the Nano S display).
+    /// 10 when not set.
+    #[clap(long, requires = "list-args")]
+    max_args: Option<usize>,
+    /// Maximum nesting depth of a listed argument's type, e.g. 2 for `List<Option<U512>>`. 2 when not set.
+    #[clap(long, requires = "list-args")]
+    max_arg_depth: Option<usize>,
+    /// Maximum number of characters of a listed argument's value. 128 when not set.
+    #[clap(long, requires = "list-args")]
+    max_arg_chars: Option<usize>,
+    /// Generates the samples for the network with the given chain name: casper (mainnet), casper-test
+    /// (testnet) or any other (custom network). Can be repeated, all the samples are generated for each
+    /// network in turn. Only for the custom `mainnet` chain when not set.
+    #[clap(long)]
+    network: Vec<Network>,
+    /// Generates only the samples of the given family: undelegate, delegate, native_transfer,
+    /// redelegate, generic, non_ascii, header_limits, cep18, cep78, add_bid, withdraw_bid,
+    /// activate_bid or auction_spoofing. Can be repeated.
+    #[clap(long)]
+    family: Vec<SampleFamily>,
+    /// Generates only the samples with the label matching the glob pattern, e.g. `redelegate__*`.
+    #[clap(long)]
+    label: Option<Pattern>,
+    /// Generates only the test vectors valid in both regular and expert mode, once the element limit is
+    /// applied.
+    #[clap(long, conflicts_with = "only-invalid")]
+    only_valid: bool,
+    /// Generates only the test vectors invalid in any of the modes, e.g. exceeding the element limit.
+    #[clap(long)]
+    only_invalid: bool,
+}
+
+impl GeneratorOpts {
+    fn rng(&self) -> TestRng {
+        match self.seed {
+            Some(seed) => TestRng::from_seed(seed),
+            None => TestRng::new(),
+        }
+    }
+
+    fn filter(&self) -> SampleFilter {
+        let valid = match (self.only_valid, self.only_invalid) {
+            (true, _) => Some(true),
+            (_, true) => Some(false),
+            _ => None,
+        };
+        SampleFilter {
+            families: self.family.clone(),
+            label: self.label.clone(),
+            valid,
+        }
+    }
+
+    /// Generates the samples matching the family and label filters, paired with their index in the full
+    /// set of samples. The validity filters depend on the ledger config, see [`GeneratorOpts::select_valid`].
+    ///
+    /// Can be called only once, as it creates the PRNG.
+    pub(crate) fn samples(&self) -> Vec<(usize, SampleFamily, Sample<Deploy>)> {
+        self.filter().samples(&mut self.rng(), &self.network)
+    }
+
+    /// Selects the samples whose test vectors, made with the config, match the validity filters.
+    pub(crate) fn select_valid(
+        &self,
+        samples: Vec<(usize, SampleFamily, Sample<Deploy>)>,
+        config: &LimitedLedgerConfig,
+    ) -> Result<Vec<(usize, SampleFamily, Sample<Deploy>)>, Box<dyn Error>> {
+        let filter = self.filter();
+        if filter.valid.is_none() {
+            return Ok(samples);
+        }
+        let mut selected = vec![];
+        for (index, family, sample) in samples {
+            let vector = ledger::deploy_to_json(index, sample.clone(), config)?;
+            if filter.matches_vector(&vector) {
+                selected.push((index, family, sample));
+            }
+        }
+        Ok(selected)
+    }
+
+    /// Generates the test vectors of the samples matching the filters.
+    ///
+    /// Can be called only once, as it creates the PRNG.
+    pub(crate) fn vectors(&self) -> Result<Vec<ZondaxRepr>, Box<dyn Error>> {
+        let filter = self.filter();
+        let samples = self
+            .samples()
+            .into_iter()
+            .map(|(index, _, sample)| (index, sample));
+        let vectors = ledger::deploys_to_json(samples, &self.ledger_config())?;
+        Ok(vectors
+            .into_iter()
+            .filter(|vector| filter.matches_vector(vector))
+            .collect())
+    }
+
+    pub(crate) fn ledger_config(&self) -> LimitedLedgerConfig {
+        self.device_ledger_config(self.device)
+    }
+
+    pub(crate) fn device_ledger_config(&self, device: DeviceProfile) -> LimitedLedgerConfig {
+        LimitedLedgerConfig::new(self.element_limit)
+            .with_device(device)
+            .with_parser_config(self.parser_config())
+    }
+
+    fn parser_config(&self) -> ParserConfig {
+        let config = ParserConfig::default().with_amount_format(self.amount_format);
+        if !self.list_args {
+            return config;
+        }
+        let defaults = ArgsLimits::default();
+        config.with_args_listing(ArgsLimits {
+            max_count: self.max_args.unwrap_or(defaults.max_count),
+            max_depth: self.max_arg_depth.unwrap_or(defaults.max_depth),
+            max_value_chars: self.max_arg_chars.unwrap_or(defaults.max_value_chars),
+        })
+    }
+}
+
+fn parse_seed(seed_as_hex: &str) -> Result<[u8; 16], String> {
+    let mut seed = [0u8; 16];
+    hex::decode_to_slice(seed_as_hex, &mut seed)
+        .map_err(|error| format!("can't parse '{}' as a seed: {}", seed_as_hex, error))?;
+    Ok(seed)
+}
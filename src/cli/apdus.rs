@@ -0,0 +1,37 @@
+use std::{error::Error, fs, path::PathBuf};
+
+use casper_deploy_generator::apdu;
+use casper_types::bytesrepr::ToBytes;
+use clap::Args;
+
+use super::GeneratorOpts;
+
+#[derive(Args)]
+pub(crate) struct ApdusArgs {
+    #[clap(flatten)]
+    generator: GeneratorOpts,
+    /// Directory to write the scripts to, one per test vector, e.g. `12_delegate__type_by_hash__payment_system.apdus`.
+    #[clap(long)]
+    output_dir: PathBuf,
+}
+
+pub(super) fn run(args: ApdusArgs) -> Result<(), Box<dyn Error>> {
+    fs::create_dir_all(&args.output_dir)?;
+    let samples = args
+        .generator
+        .select_valid(args.generator.samples(), &args.generator.ledger_config())?;
+    for (index, _, sample) in samples {
+        let (name, deploy, _) = sample.destructure();
+        let blob = deploy
+            .to_bytes()
+            .map_err(|err| format!("can't serialize deploy {}: {}", name, err))?;
+        // One hex-encoded APDU per line, in the order they are sent to the device.
+        let script: String = apdu::sign_apdus(&blob)
+            .iter()
+            .map(|apdu| format!("{}\n", apdu.to_hex()))
+            .collect();
+        let path = args.output_dir.join(format!("{}_{}.apdus", index, name));
+        fs::write(path, script)?;
+    }
+    Ok(())
+}
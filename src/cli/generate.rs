@@ -0,0 +1,184 @@
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use casper_deploy_generator::{
+    ledger::{self, DeviceProfile, LimitedLedgerConfig, ZondaxRepr},
+    sample::Sample,
+    test_data::SampleFamily,
+    TestSigner,
+};
+use casper_node::types::Deploy;
+use clap::Args;
+use serde::Serialize;
+
+use super::GeneratorOpts;
+
+/// Name of the file listing the per-family test vectors files.
+const INDEX_FILE: &str = "index.json";
+
+#[derive(Args)]
+pub(crate) struct GenerateArgs {
+    #[clap(flatten)]
+    generator: GeneratorOpts,
+    /// File to write the test vectors to. Prints to stdout when not set.
+    #[clap(long, short)]
+    output: Option<PathBuf>,
+    /// Directory to write the test vectors to, one file per sample family (e.g. `delegate.json`)
+    /// and an `index.json` file listing them.
+    #[clap(long, conflicts_with = "output")]
+    output_dir: Option<PathBuf>,
+    /// Generates a set of test vectors for every device model.
+    /// Each set is written to the `--output` file with the device name appended, e.g. `manual_nanox.json`,
+    /// or to a subdirectory of `--output-dir` named after the device.
+    #[clap(long, conflicts_with = "device")]
+    all_devices: bool,
+    #[clap(flatten)]
+    extras: Extras,
+}
+
+/// Optional fields added to every test vector.
+#[derive(Args)]
+struct Extras {
+    /// Adds the APDUs sending the transaction to the app for signing to every test vector.
+    #[clap(long)]
+    apdus: bool,
+    /// Adds the signature expected from a test device (Zemu, Speculos) to every valid test vector.
+    #[clap(long)]
+    signatures: bool,
+}
+
+impl Extras {
+    fn add(&self, vectors: Vec<ZondaxRepr>) -> Result<Vec<ZondaxRepr>, Box<dyn Error>> {
+        let signer = self.signatures.then(TestSigner::new);
+        vectors
+            .into_iter()
+            .map(|mut vector| -> Result<ZondaxRepr, Box<dyn Error>> {
+                if self.apdus {
+                    vector = vector.with_apdus()?;
+                }
+                if let Some(signer) = &signer {
+                    vector = vector.with_signature(signer)?;
+                }
+                Ok(vector)
+            })
+            .collect()
+    }
+}
+
+/// Entry of the index file.
+#[derive(Serialize)]
+struct IndexEntry {
+    family: String,
+    file: String,
+    count: usize,
+}
+
+pub(super) fn run(args: GenerateArgs) -> Result<(), Box<dyn Error>> {
+    if args.all_devices {
+        if args.output.is_none() && args.output_dir.is_none() {
+            return Err("--all-devices requires --output or --output-dir".into());
+        }
+        let samples = args.generator.samples();
+        for device in DeviceProfile::ALL {
+            let config = args.generator.device_ledger_config(device);
+            // Validity depends on the device, e.g. on how many pages the values span.
+            let samples = args.generator.select_valid(samples.clone(), &config)?;
+            match (&args.output_dir, &args.output) {
+                (Some(dir), _) => {
+                    write_family_files(&dir.join(device.name), &samples, &config, &args.extras)?
+                }
+                (None, output) => {
+                    let output = output.as_deref().map(|path| device_path(path, &device));
+                    write_vectors(
+                        &vectors(&samples, &config, &args.extras)?,
+                        output.as_deref(),
+                    )?
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    let config = args.generator.ledger_config();
+    let samples = args
+        .generator
+        .select_valid(args.generator.samples(), &config)?;
+    match &args.output_dir {
+        Some(dir) => write_family_files(dir, &samples, &config, &args.extras),
+        None => write_vectors(
+            &vectors(&samples, &config, &args.extras)?,
+            args.output.as_deref(),
+        ),
+    }
+}
+
+fn vectors(
+    samples: &[(usize, SampleFamily, Sample<Deploy>)],
+    config: &LimitedLedgerConfig,
+    extras: &Extras,
+) -> Result<Vec<ZondaxRepr>, Box<dyn Error>> {
+    let samples = samples
+        .iter()
+        .map(|(index, _, sample)| (*index, sample.clone()));
+    extras.add(ledger::deploys_to_json(samples, config)?)
+}
+
+fn write_vectors(data: &[ZondaxRepr], output: Option<&Path>) -> Result<(), Box<dyn Error>> {
+    let json = serde_json::to_string_pretty(data)?;
+    match output {
+        Some(path) => fs::write(path, format!("{}\n", json))?,
+        None => println!("{}", json),
+    }
+    Ok(())
+}
+
+// Writes the test vectors of every sample family to its own file in `dir`, followed by the index file.
+// Families left without samples by the filters get no file.
+fn write_family_files(
+    dir: &Path,
+    samples: &[(usize, SampleFamily, Sample<Deploy>)],
+    config: &LimitedLedgerConfig,
+    extras: &Extras,
+) -> Result<(), Box<dyn Error>> {
+    fs::create_dir_all(dir)?;
+    let mut index = vec![];
+    for family in SampleFamily::ALL {
+        let family_samples: Vec<_> = samples
+            .iter()
+            .filter(|(_, sample_family, _)| *sample_family == family)
+            .cloned()
+            .collect();
+        if family_samples.is_empty() {
+            continue;
+        }
+        let file = format!("{}.json", family);
+        write_vectors(
+            &vectors(&family_samples, config, extras)?,
+            Some(&dir.join(&file)),
+        )?;
+        index.push(IndexEntry {
+            family: family.to_string(),
+            file,
+            count: family_samples.len(),
+        });
+    }
+    let json = serde_json::to_string_pretty(&index)?;
+    fs::write(dir.join(INDEX_FILE), format!("{}\n", json))?;
+    Ok(())
+}
+
+// Appends the device name to the file name: `manual.json` -> `manual_nanox.json`.
+fn device_path(path: &Path, device: &DeviceProfile) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let mut file_name = format!("{}_{}", stem, device);
+    if let Some(extension) = path.extension() {
+        file_name = format!("{}.{}", file_name, extension.to_string_lossy());
+    }
+    path.with_file_name(file_name)
+}
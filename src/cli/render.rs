@@ -0,0 +1,113 @@
+use std::{
+    error::Error,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use casper_deploy_generator::{
+    ledger::{self, ZondaxRepr},
+    sample::Sample,
+};
+use casper_node::types::Deploy;
+use clap::{ArgGroup, Args};
+
+use super::GeneratorOpts;
+
+#[derive(Args)]
+#[clap(group(ArgGroup::new("deploy").required(true).args(&["index", "name", "input", "blob"])))]
+pub(crate) struct RenderArgs {
+    #[clap(flatten)]
+    generator: GeneratorOpts,
+    /// Index of the generated test vector to render.
+    #[clap(long)]
+    index: Option<usize>,
+    /// Name of the generated test vector to render.
+    #[clap(long)]
+    name: Option<String>,
+    /// File with a deploy in its JSON form (as printed by casper-client) to render.
+    /// Reads from stdin when set to `-`.
+    #[clap(long)]
+    input: Option<PathBuf>,
+    /// Hex-encoded, serialized deploy (as in the `blob` field of a test vector) to render.
+    #[clap(long)]
+    blob: Option<String>,
+    /// Prints the whole test vector in its JSON form instead of the list of pages.
+    #[clap(long)]
+    json: bool,
+}
+
+pub(super) fn run(args: RenderArgs) -> Result<(), Box<dyn Error>> {
+    let config = args.generator.ledger_config();
+    let vector = match (&args.input, &args.blob) {
+        (Some(input), _) => {
+            let deploy: Deploy = serde_json::from_str(&read_input(input)?)?;
+            let sample = Sample::new(input_name(input), deploy, true);
+            ledger::deploy_to_json(0, sample, &config)?
+        }
+        (None, Some(blob)) => {
+            let deploy = ledger::deploy_from_blob(blob)?;
+            ledger::deploy_to_json(0, Sample::new("blob", deploy, true), &config)?
+        }
+        (None, None) => generated_vector(&args)?,
+    };
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&vector)?);
+    } else {
+        print_vector(&vector);
+    }
+    Ok(())
+}
+
+fn generated_vector(args: &RenderArgs) -> Result<ZondaxRepr, Box<dyn Error>> {
+    let vector = args
+        .generator
+        .vectors()?
+        .into_iter()
+        .find(|vector| match (&args.index, &args.name) {
+            (Some(index), _) => vector.index == *index,
+            (None, Some(name)) => &vector.name == name,
+            (None, None) => false,
+        })
+        .ok_or("no test vector matches the criteria")?;
+    Ok(vector)
+}
+
+/// Reads the whole input file, or stdin when `path` is `-`.
+pub(super) fn read_input(path: &Path) -> io::Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+fn input_name(path: &Path) -> String {
+    if path == Path::new("-") {
+        "stdin".to_string()
+    } else {
+        path.display().to_string()
+    }
+}
+
+/// Prints the regular and expert Ledger pages of the test vector.
+pub(super) fn print_vector(vector: &ZondaxRepr) {
+    println!("{} | {}", vector.index, vector.name);
+    if let Some(error) = &vector.error {
+        println!("Error: {}", error);
+    }
+    println!();
+    println!("Regular:");
+    print_pages(&vector.output);
+    println!();
+    println!("Expert:");
+    print_pages(&vector.output_expert);
+}
+
+fn print_pages(pages: &[String]) {
+    for page in pages {
+        println!("  {}", page);
+    }
+}
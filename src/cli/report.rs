@@ -0,0 +1,196 @@
+use std::{error::Error, fs, path::PathBuf};
+
+use casper_deploy_generator::{
+    ledger::{self, LedgerView, LimitedLedgerConfig, ZondaxRepr},
+    sample::Sample,
+    test_data::SampleFamily,
+};
+use clap::Args;
+
+use super::GeneratorOpts;
+
+#[derive(Args)]
+pub(crate) struct ReportArgs {
+    #[clap(flatten)]
+    generator: GeneratorOpts,
+    /// File to write the HTML report to.
+    #[clap(long, short, default_value = "report.html")]
+    output: PathBuf,
+}
+
+const STYLE: &str = "
+body { font-family: sans-serif; margin: 2em; }
+.filters { position: sticky; top: 0; background: white; padding: 1em 0; border-bottom: 1px solid #ccc; }
+.filters label { margin-right: 1em; }
+.vector { border-bottom: 1px solid #ccc; padding: 1em 0; }
+.vector h2 { font-size: 1.1em; font-family: monospace; }
+.meta span { margin-right: 1.5em; }
+.invalid { color: #b00020; }
+.exceeded { color: #b00020; font-weight: bold; }
+.error { color: #b00020; font-family: monospace; }
+.modes { display: flex; gap: 2em; }
+.modes > div { flex: 1; min-width: 0; }
+.modes pre { background: #f4f4f4; padding: 0.5em; overflow-x: auto; }
+";
+
+// Shows only the vectors of the checked families with the selected validity.
+const SCRIPT: &str = "
+function applyFilters() {
+  const families = Array.from(document.querySelectorAll('input[name=family]:checked')).map(input => input.value);
+  const validity = document.getElementById('validity').value;
+  for (const vector of document.querySelectorAll('.vector')) {
+    const familyMatches = families.includes(vector.dataset.family);
+    const validityMatches = validity === 'all' || vector.dataset.valid === validity;
+    vector.hidden = !(familyMatches && validityMatches);
+  }
+}
+document.querySelectorAll('.filters input, .filters select')
+  .forEach(input => input.addEventListener('change', applyFilters));
+";
+
+pub(super) fn run(args: ReportArgs) -> Result<(), Box<dyn Error>> {
+    let config = args.generator.ledger_config();
+    let mut sections = vec![];
+    let samples = args
+        .generator
+        .select_valid(args.generator.samples(), &config)?;
+    for (index, family, sample) in samples {
+        let (name, deploy, valid) = sample.destructure();
+        // Element counts of the whole deploy, before it's replaced for exceeding the element limit.
+        let view =
+            LedgerView::from_deploy(deploy.clone(), config.parser_config(), config.device()).ok();
+        let vector = ledger::deploy_to_json(index, Sample::new(name, deploy, valid), &config)?;
+        sections.push(vector_section(family, &vector, view.as_ref(), &config));
+    }
+
+    let html = format!(
+        "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<title>Casper Ledger test vectors</title>
+<style>{}</style>
+</head>
+<body>
+<h1>Casper Ledger test vectors</h1>
+<p>Device: {}, element limit: {}, test vectors: {}</p>
+{}
+{}
+<script>{}</script>
+</body>
+</html>
+",
+        STYLE,
+        config.device(),
+        config.element_limit(),
+        sections.len(),
+        filters(),
+        sections.join("\n"),
+        SCRIPT
+    );
+    fs::write(&args.output, html)?;
+    Ok(())
+}
+
+fn filters() -> String {
+    let families: Vec<String> = SampleFamily::ALL
+        .iter()
+        .map(|family| {
+            format!(
+                "<label><input type=\"checkbox\" name=\"family\" value=\"{0}\" checked> {0}</label>",
+                family
+            )
+        })
+        .collect();
+    format!(
+        "<div class=\"filters\">
+{}
+<label>Validity <select id=\"validity\">
+<option value=\"all\">all</option>
+<option value=\"valid\">valid</option>
+<option value=\"invalid\">invalid in any mode</option>
+</select></label>
+</div>",
+        families.join("\n")
+    )
+}
+
+fn vector_section(
+    family: SampleFamily,
+    vector: &ZondaxRepr,
+    view: Option<&LedgerView>,
+    config: &LimitedLedgerConfig,
+) -> String {
+    let valid = vector.valid_regular && vector.valid_expert;
+    let mut meta = vec![
+        flag("valid (regular)", vector.valid_regular),
+        flag("valid (expert)", vector.valid_expert),
+        format!("<span>testnet: {}</span>", vector.testnet),
+        format!("<span>blob: {} bytes</span>", vector.blob.len() / 2),
+    ];
+    if let Some(view) = view {
+        meta.push(element_count("regular", view.element_count(false), config));
+        meta.push(element_count("expert", view.element_count(true), config));
+    }
+    if vector.sanitized {
+        meta.push("<span>sanitized</span>".to_string());
+    }
+    let error = vector
+        .error
+        .as_ref()
+        .map(|error| format!("<p class=\"error\">{}</p>\n", escape_html(error)))
+        .unwrap_or_default();
+    format!(
+        "<section class=\"vector\" data-family=\"{}\" data-valid=\"{}\">
+<h2>{} | {}</h2>
+<p class=\"meta\">{}</p>
+{}<div class=\"modes\">
+<div><h3>Regular</h3>{}</div>
+<div><h3>Expert</h3>{}</div>
+</div>
+</section>",
+        family,
+        if valid { "valid" } else { "invalid" },
+        vector.index,
+        escape_html(&vector.name),
+        meta.join(" "),
+        error,
+        pages(&vector.output, vector.limit_exceeded_regular),
+        pages(&vector.output_expert, vector.limit_exceeded_expert),
+    )
+}
+
+fn flag(name: &str, value: bool) -> String {
+    let class = if value { "" } else { " class=\"invalid\"" };
+    format!("<span{}>{}: {}</span>", class, name, value)
+}
+
+fn element_count(mode: &str, count: usize, config: &LimitedLedgerConfig) -> String {
+    let limit = config.element_limit() as usize;
+    let class = if count > limit {
+        " class=\"exceeded\""
+    } else {
+        ""
+    };
+    format!(
+        "<span{}>{} elements: {}/{}</span>",
+        class, mode, count, limit
+    )
+}
+
+fn pages(pages: &[String], limit_exceeded: bool) -> String {
+    let notice = if limit_exceeded {
+        "<p class=\"exceeded\">Element limit exceeded, output replaced</p>"
+    } else {
+        ""
+    };
+    let lines: Vec<String> = pages.iter().map(|page| escape_html(page)).collect();
+    format!("{}<pre>{}</pre>", notice, lines.join("\n"))
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
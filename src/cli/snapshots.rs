@@ -0,0 +1,100 @@
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use casper_deploy_generator::ledger::{self, DeviceProfile, LedgerView, Screen};
+use clap::Args;
+
+use super::GeneratorOpts;
+
+#[derive(Args)]
+pub(crate) struct SnapshotsArgs {
+    #[clap(flatten)]
+    generator: GeneratorOpts,
+    /// Directory to write the snapshots to.
+    /// Every test vector gets its own subdirectory, e.g. `12_delegate__type_by_hash__payment_system`,
+    /// with one file per screen in its `regular` and `expert` subdirectories.
+    #[clap(long)]
+    output_dir: PathBuf,
+    /// Format of the snapshots: ascii or svg.
+    #[clap(long, default_value = "ascii")]
+    format: SnapshotFormat,
+}
+
+#[derive(Clone, Copy)]
+enum SnapshotFormat {
+    Ascii,
+    Svg,
+}
+
+impl SnapshotFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            SnapshotFormat::Ascii => "txt",
+            SnapshotFormat::Svg => "svg",
+        }
+    }
+
+    fn draw(&self, screen: &Screen, device: &DeviceProfile) -> String {
+        match self {
+            SnapshotFormat::Ascii => screen.to_ascii(device),
+            SnapshotFormat::Svg => screen.to_svg(device),
+        }
+    }
+}
+
+impl FromStr for SnapshotFormat {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "ascii" => Ok(SnapshotFormat::Ascii),
+            "svg" => Ok(SnapshotFormat::Svg),
+            _ => Err(format!(
+                "unknown snapshot format '{}', expected ascii or svg",
+                name
+            )),
+        }
+    }
+}
+
+pub(super) fn run(args: SnapshotsArgs) -> Result<(), Box<dyn Error>> {
+    let config = args.generator.ledger_config();
+    let device = config.device();
+    let samples = args
+        .generator
+        .select_valid(args.generator.samples(), &config)?;
+    for (index, _, sample) in samples {
+        let (name, deploy, _) = sample.destructure();
+        let dir = args.output_dir.join(format!("{}_{}", index, name));
+        fs::create_dir_all(&dir)?;
+        match ledger::limited_views(deploy, &config) {
+            Ok((regular, expert)) => {
+                write_screens(&dir.join("regular"), &regular, false, device, args.format)?;
+                write_screens(&dir.join("expert"), &expert, true, device, args.format)?;
+            }
+            // Ledger can't display the deploy, there are no screens.
+            Err(error) => fs::write(dir.join("error.txt"), format!("{}\n", error))?,
+        }
+    }
+    Ok(())
+}
+
+// Writes every screen displayed in the chosen mode to its own file, numbered like Zemu's snapshots: `00000.txt`, ...
+fn write_screens(
+    dir: &Path,
+    view: &LedgerView,
+    expert: bool,
+    device: &DeviceProfile,
+    format: SnapshotFormat,
+) -> Result<(), Box<dyn Error>> {
+    fs::create_dir_all(dir)?;
+    for (idx, screen) in view.screens(expert).iter().enumerate() {
+        let path = dir.join(format!("{:05}.{}", idx, format.extension()));
+        fs::write(path, format.draw(screen, device))?;
+    }
+    Ok(())
+}
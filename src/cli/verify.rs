@@ -0,0 +1,227 @@
+use std::{collections::BTreeMap, error::Error, path::PathBuf};
+
+use casper_deploy_generator::{
+    ledger::ZondaxRepr,
+    vectors::{self, IndexCheck},
+    TestSigner,
+};
+use clap::Args;
+
+use super::GeneratorOpts;
+
+#[derive(Args)]
+pub(crate) struct VerifyArgs {
+    #[clap(flatten)]
+    generator: GeneratorOpts,
+    /// Test vectors file to check.
+    #[clap(default_value = "manual.json")]
+    input: PathBuf,
+    /// Accept gaps in the indices of the test vectors, e.g. of a file generated with the filters.
+    #[clap(long)]
+    allow_index_gaps: bool,
+}
+
+pub(super) fn run(args: VerifyArgs) -> Result<(), Box<dyn Error>> {
+    let indices = if args.allow_index_gaps {
+        IndexCheck::AllowGaps
+    } else {
+        IndexCheck::Contiguous
+    };
+    let existing = vectors::load(&args.input, indices)?;
+    let mut fresh = args.generator.vectors()?;
+    // APDUs and signatures are optional, they are checked only if the existing file has them.
+    if existing.iter().any(|vector| vector.apdus.is_some()) {
+        fresh = fresh
+            .into_iter()
+            .map(ZondaxRepr::with_apdus)
+            .collect::<Result<_, _>>()?;
+    }
+    if existing.iter().any(|vector| vector.signature.is_some()) {
+        let signer = TestSigner::new();
+        fresh = fresh
+            .into_iter()
+            .map(|vector| vector.with_signature(&signer))
+            .collect::<Result<_, _>>()?;
+    }
+
+    let diff = VectorsDiff::new(&existing, &fresh);
+    if diff.is_empty() {
+        println!("{} test vectors match", fresh.len());
+        return Ok(());
+    }
+    diff.print();
+    Err(format!(
+        "{} does not match the generated test vectors",
+        args.input.display()
+    )
+    .into())
+}
+
+// Test vectors are matched by both their index and name.
+type VectorKey<'a> = (usize, &'a str);
+
+fn by_key<'a>(vectors: &'a [ZondaxRepr]) -> BTreeMap<VectorKey<'a>, &'a ZondaxRepr> {
+    vectors
+        .iter()
+        .map(|vector| ((vector.index, vector.name.as_str()), vector))
+        .collect()
+}
+
+/// Differences between an existing set of test vectors and a freshly generated one.
+struct VectorsDiff<'a> {
+    // Vectors present only in the generated set.
+    added: Vec<&'a ZondaxRepr>,
+    // Vectors present only in the existing set.
+    removed: Vec<&'a ZondaxRepr>,
+    // Pairs of (existing, generated) vectors that differ.
+    changed: Vec<(&'a ZondaxRepr, &'a ZondaxRepr)>,
+}
+
+impl<'a> VectorsDiff<'a> {
+    fn new(existing: &'a [ZondaxRepr], fresh: &'a [ZondaxRepr]) -> Self {
+        let existing = by_key(existing);
+        let fresh = by_key(fresh);
+
+        let added = fresh
+            .iter()
+            .filter(|(key, _)| !existing.contains_key(*key))
+            .map(|(_, vector)| *vector)
+            .collect();
+        let mut removed = vec![];
+        let mut changed = vec![];
+        for (key, old) in &existing {
+            match fresh.get(key) {
+                None => removed.push(*old),
+                Some(new) if old != new => changed.push((*old, *new)),
+                Some(_) => {}
+            }
+        }
+
+        VectorsDiff {
+            added,
+            removed,
+            changed,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    fn print(&self) {
+        for vector in &self.removed {
+            println!("removed: {} | {}", vector.index, vector.name);
+        }
+        for vector in &self.added {
+            println!("added: {} | {}", vector.index, vector.name);
+        }
+        for (old, new) in &self.changed {
+            println!("changed: {} | {}", old.index, old.name);
+            print_flag("valid_regular", old.valid_regular, new.valid_regular);
+            print_flag("valid_expert", old.valid_expert, new.valid_expert);
+            print_flag("testnet", old.testnet, new.testnet);
+            print_flag("sanitized", old.sanitized, new.sanitized);
+            if old.blob != new.blob {
+                println!("  blob changed");
+            }
+            if old.apdus != new.apdus {
+                println!("  apdus changed");
+            }
+            if old.signature != new.signature {
+                println!("  signature changed");
+            }
+            if old.error != new.error {
+                println!("  error: {:?} -> {:?}", old.error, new.error);
+            }
+            print_pages_diff("output", &old.output, &new.output);
+            print_pages_diff("output_expert", &old.output_expert, &new.output_expert);
+        }
+        println!(
+            "{} added, {} removed, {} changed",
+            self.added.len(),
+            self.removed.len(),
+            self.changed.len()
+        );
+    }
+}
+
+fn print_flag(name: &str, old: bool, new: bool) {
+    if old != new {
+        println!("  {}: {} -> {}", name, old, new);
+    }
+}
+
+fn print_pages_diff(name: &str, old: &[String], new: &[String]) {
+    if old == new {
+        return;
+    }
+    println!("  {}:", name);
+    for line in diff_pages(old, new) {
+        println!("    {}", line);
+    }
+}
+
+/// Line diff of two lists of pages, based on their longest common subsequence.
+/// Unchanged pages are prefixed with ' ', removed ones with '-' and added ones with '+'.
+fn diff_pages(old: &[String], new: &[String]) -> Vec<String> {
+    // lcs[i][j] is the length of the longest common subsequence of `old[i..]` and `new[j..]`.
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut output = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            output.push(format!("  {}", old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            output.push(format!("- {}", old[i]));
+            i += 1;
+        } else {
+            output.push(format!("+ {}", new[j]));
+            j += 1;
+        }
+    }
+    output.extend(old[i..].iter().map(|page| format!("- {}", page)));
+    output.extend(new[j..].iter().map(|page| format!("+ {}", page)));
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::diff_pages;
+
+    fn pages(pages: &[&str]) -> Vec<String> {
+        pages.iter().map(|page| page.to_string()).collect()
+    }
+
+    #[test]
+    fn pages_diff() {
+        let old = pages(&[
+            "0 | Type : Delegate",
+            "1 | Fee : 1 motes",
+            "2 | Amount : 0 motes",
+        ]);
+        let new = pages(&[
+            "0 | Type : Delegate",
+            "1 | Fee : 2 motes",
+            "2 | Amount : 0 motes",
+        ]);
+        let expected = pages(&[
+            "  0 | Type : Delegate",
+            "- 1 | Fee : 1 motes",
+            "+ 1 | Fee : 2 motes",
+            "  2 | Amount : 0 motes",
+        ]);
+        assert_eq!(expected, diff_pages(&old, &new));
+    }
+}
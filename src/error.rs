@@ -0,0 +1,57 @@
+use std::fmt::Display;
+
+use casper_types::CLType;
+
+/// Reasons why a deploy can't be displayed in Ledger.
+///
+/// Ledger rejects such deploys, so instead of failing the whole run they are turned into invalid test vectors.
+/// Also covers the test vectors' blobs that don't decode to a deploy, and the deploys that don't encode to one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// Label of an element doesn't fit in the label row of the device's display.
+    LabelTooLong { label: String, max_chars: usize },
+    /// The system public key, which can't be displayed (nor sign deploys).
+    SystemPublicKey,
+    /// Bytes of a `CLValue` don't deserialize into its declared type.
+    InvalidCLValue { cl_type: CLType, reason: String },
+    /// `CLValue` can't be turned into its JSON representation.
+    CLValueJson(String),
+    /// Runtime argument is not of the expected type.
+    UnexpectedArgType { name: String, expected: CLType },
+    /// Amount argument is not a valid number of motes.
+    InvalidAmount(String),
+    /// Deploy item can't be used to call the entry point.
+    UnexpectedDeployItem { entry_point: String },
+    /// Test vector's blob isn't a hex-encoded, serialized deploy.
+    InvalidBlob(String),
+    /// Deploy can't be serialized into a test vector's blob.
+    Serialization(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::LabelTooLong { label, max_chars } => write!(
+                f,
+                "label '{}' is longer than {} characters",
+                label, max_chars
+            ),
+            Error::SystemPublicKey => write!(f, "unexpected system public key"),
+            Error::InvalidCLValue { cl_type, reason } => {
+                write!(f, "invalid CLValue of type {:?}: {}", cl_type, reason)
+            }
+            Error::CLValueJson(reason) => write!(f, "can't parse CLValue as JSON: {}", reason),
+            Error::UnexpectedArgType { name, expected } => {
+                write!(f, "argument '{}' should be of type {:?}", name, expected)
+            }
+            Error::InvalidAmount(amount) => write!(f, "invalid amount '{}'", amount),
+            Error::UnexpectedDeployItem { entry_point } => {
+                write!(f, "unexpected deploy item for {}", entry_point)
+            }
+            Error::InvalidBlob(reason) => write!(f, "invalid blob: {}", reason),
+            Error::Serialization(reason) => write!(f, "can't serialize deploy: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
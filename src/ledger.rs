@@ -1,18 +1,28 @@
-use std::{fmt::Display, rc::Rc};
+use std::{borrow::Cow, fmt::Display, rc::Rc};
 
 use casper_node::types::Deploy;
-use casper_types::bytesrepr::ToBytes;
+use casper_types::bytesrepr::{self, ToBytes};
 
 use serde::{Deserialize, Serialize};
 
-use crate::{parser, sample::Sample};
-
-// Character limit for Ledger's "label" row.
-const LEDGER_VIEW_NAME_CHAR_COUNT: usize = 11;
-// Character limit for Ledger's value top row.
-const LEDGER_VIEW_TOP_ROW_CHAR_COUNT: usize = 17;
-// Character limit for Ledger's value bottom row.
-const LEDGER_VIEW_BOTTOM_CHAR_COUNT: usize = 17;
+use crate::{
+    apdu::{self, Apdu},
+    error::Error,
+    parser::{self, ParserConfig},
+    sample::Sample,
+    test_signer::TestSigner,
+    Network,
+};
+
+mod device;
+mod sanitize;
+mod screen;
+mod value_kind;
+
+pub use device::DeviceProfile;
+pub use sanitize::sanitize;
+pub use screen::Screen;
+pub use value_kind::ValueKind;
 
 #[derive(Clone, Copy)]
 pub(crate) enum TxnPhase {
@@ -35,15 +45,34 @@ impl Display for TxnPhase {
     }
 }
 
+// Modes an element is displayed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Visibility {
+    Always,
+    ExpertOnly,
+    RegularOnly,
+}
+
+impl Visibility {
+    fn is_visible(&self, expert: bool) -> bool {
+        match self {
+            Visibility::Always => true,
+            Visibility::ExpertOnly => expert,
+            Visibility::RegularOnly => !expert,
+        }
+    }
+}
+
 /// A single element of the transaction to be displayed in Ledger.
 #[derive(Debug, Clone)]
-pub(crate) struct Element {
+pub struct Element {
     /// Label of the element to display - like `from`, `to`, `amount`.
     name: String,
     /// Value of the element.
     value: String,
-    // Whether to display in expert mode only.
-    expert: bool,
+    /// Kind of the value, deciding how it's split into rows.
+    kind: ValueKind,
+    visibility: Visibility,
 }
 
 // Capitalizes the first character.
@@ -57,26 +86,70 @@ fn capitalize_first(s: &str) -> String {
 
 impl Element {
     /// Creates an instance of the element, marking it as to be displayed in expert-only mode.
-    pub(crate) fn expert(name: &str, value: String) -> Element {
+    pub fn expert(name: &str, value: String) -> Element {
+        Element {
+            name: capitalize_first(name),
+            value,
+            kind: ValueKind::default(),
+            visibility: Visibility::ExpertOnly,
+        }
+    }
+
+    /// Creates an instance of the element, marking it as to be displayed in both regular and expert mode.
+    pub fn regular(name: &str, value: String) -> Self {
         Element {
             name: capitalize_first(name),
             value,
-            expert: true,
+            kind: ValueKind::default(),
+            visibility: Visibility::Always,
         }
     }
 
-    /// Creates an instance of the element, marking it as to be displayed in regular mode.
-    pub(crate) fn regular(name: &str, value: String) -> Self {
+    /// Creates an instance of the element, marking it as to be displayed in regular mode only -
+    /// for a shortened form of a value that expert mode displays in full.
+    pub fn regular_only(name: &str, value: String) -> Self {
         Element {
             name: capitalize_first(name),
             value,
-            expert: false,
+            kind: ValueKind::default(),
+            visibility: Visibility::RegularOnly,
         }
     }
 
+    /// Sets the kind of the value. Values are `ValueKind::Text` by default.
+    pub fn with_kind(mut self, kind: ValueKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
     /// Flips the "expert" bit to `true`.
     pub(crate) fn as_expert(&mut self) {
-        self.expert = true;
+        self.visibility = Visibility::ExpertOnly;
+    }
+
+    /// Label of the element.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Value of the element, before it is split into pages.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Kind of the value.
+    pub fn kind(&self) -> ValueKind {
+        self.kind
+    }
+
+    /// Whether the element is displayed in expert mode only.
+    pub fn is_expert(&self) -> bool {
+        self.visibility == Visibility::ExpertOnly
+    }
+
+    /// Whether the element is displayed in the chosen mode.
+    pub fn is_visible(&self, expert: bool) -> bool {
+        self.visibility.is_visible(expert)
     }
 }
 
@@ -88,155 +161,233 @@ struct Ledger {
 }
 
 impl Ledger {
-    fn from_deploy(deploy: Deploy) -> Self {
-        Ledger {
+    fn from_deploy(deploy: Deploy, config: &ParserConfig) -> Result<Self, Error> {
+        Ok(Ledger {
             deploy: deploy.clone(),
-            ledger_elements: parser::parse_deploy(deploy),
-        }
+            ledger_elements: parser::parse_deploy(deploy, config)?,
+        })
     }
 
     pub(crate) fn into_ledger_elements(self) -> impl Iterator<Item = Element> {
         self.ledger_elements.into_iter()
     }
+
+    // Returns copies of the elements with one of the given labels, in their original order.
+    fn elements_labeled(&self, labels: &[&str]) -> Vec<Element> {
+        self.ledger_elements
+            .iter()
+            .filter(|element| labels.contains(&element.name.as_str()))
+            .cloned()
+            .collect()
+    }
 }
 
+/// Value of an element displayed on a single Ledger screen.
 #[derive(Default, Clone)]
-struct LedgerValue {
-    top: String,
-    bottom: String,
+pub struct LedgerValue {
+    rows: Vec<String>,
 }
 
 impl LedgerValue {
-    // Adds a char to the ledger value.
-    // Single value is limited by the number of chars that can be
-    // printed on one ledger view: 34 char total in two lines.
-    // Function first tries to add a new char to the top row, if that is full
-    // then tries to add it to the bottom row.
-    // Returns whether adding char was successful.
-    fn add_char(&mut self, c: char) -> bool {
-        if self.top.chars().count() < LEDGER_VIEW_TOP_ROW_CHAR_COUNT {
-            self.top = format!("{}{}", self.top, c);
-            return true;
-        }
-        if self.bottom.chars().count() < LEDGER_VIEW_BOTTOM_CHAR_COUNT {
-            self.bottom = format!("{}{}", self.bottom, c);
-            return true;
-        }
-        false
+    // Concatenates all rows into single `String`.
+    fn into_str(&self) -> String {
+        self.rows.concat()
     }
 
-    // Concatenates both rows into single `String`.
-    fn into_str(&self) -> String {
-        format!("{}{}", self.top, self.bottom)
+    /// Rows of the value, as laid out on the device's display.
+    pub fn rows(&self) -> &[String] {
+        &self.rows
     }
 }
 
 impl std::fmt::Display for LedgerValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}{}", self.top, self.bottom)
+        write!(f, "{}", self.rows.concat())
     }
 }
 
-// Single Ledger page view representation.
-// Example:
-// Hash [1/2]
-// 01001010101…
-// 10101010101…
-//
-// When displayed can span multiple pages: 1/n
+/// Single Ledger page view representation.
+/// Example:
+/// ```text
+/// Hash [1/2]
+/// 01001010101…
+/// 10101010101…
+/// ```
+///
+/// When displayed can span multiple pages: 1/n
 #[derive(Default, Clone)]
-struct LedgerPageView {
+pub struct LedgerPageView {
     // Name of the panel, like hash, chain name, sender, etc.
     name: String,
-    // Whether element is for expert mode only.
-    expert: bool,
+    // Modes the page is displayed in.
+    visibility: Visibility,
     values: Vec<LedgerValue>,
+    // Whether the value had characters that Ledger can't display.
+    sanitized: bool,
 }
 
 impl LedgerPageView {
     /// Parses an `Element` object (which represents a single piece of a transaction) into a Ledger representation -
     /// including chopping up the string representation of the `Element` so that they can fit on a single Ledger screen.
-    fn from_element(element: Element) -> Self {
-        if element.name.chars().count() > LEDGER_VIEW_NAME_CHAR_COUNT {
-            panic!(
-                "Name tag can only be {} elements. Tag: {}",
-                LEDGER_VIEW_NAME_CHAR_COUNT, element.name
-            )
+    ///
+    /// Characters that Ledger can't display are escaped (see [`sanitize()`]).
+    ///
+    /// Fails when the label of the element doesn't fit on the device's label row.
+    pub fn from_element(element: Element, device: &DeviceProfile) -> Result<Self, Error> {
+        if element.name.chars().count() > device.label_chars {
+            return Err(Error::LabelTooLong {
+                label: element.name,
+                max_chars: device.label_chars,
+            });
         }
-        let mut values = vec![];
-        let mut curr_value = LedgerValue::default();
-        for c in element.value.chars() {
-            let added = curr_value.add_char(c);
-            if !added {
-                // Single ledger page can't contain more characters.
-                values.push(curr_value.clone());
-                // Create new Ledger page for that element.
-                curr_value = LedgerValue::default();
-                assert!(curr_value.add_char(c));
-            }
+        // Single value is limited by the number of rows that can be
+        // printed on one ledger view: `value_rows` rows, `value_row_chars` each.
+        let value = sanitize(&element.value);
+        let sanitized = matches!(value, Cow::Owned(_));
+        let rows = element.kind.wrap(&value, device.value_row_chars);
+        let mut values: Vec<LedgerValue> = rows
+            .chunks(device.value_rows)
+            .map(|rows| LedgerValue {
+                rows: rows.to_vec(),
+            })
+            .collect();
+        if values.is_empty() {
+            // Empty value is still displayed on a single page.
+            values.push(LedgerValue::default());
         }
-        // Add the last view to the collection.
-        values.push(curr_value);
 
-        LedgerPageView {
+        Ok(LedgerPageView {
             name: element.name.clone(),
-            expert: element.expert,
+            visibility: element.visibility,
             values,
-        }
+            sanitized,
+        })
     }
 
-    /// Turn the current element into printable Ledger views.
-    /// Adds indexes and labels.
-    fn to_string(&self) -> Vec<String> {
+    /// Label of the page.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Whether the page is displayed in expert mode only.
+    pub fn is_expert(&self) -> bool {
+        self.visibility == Visibility::ExpertOnly
+    }
+
+    /// Whether the page is displayed in the chosen mode.
+    pub fn is_visible(&self, expert: bool) -> bool {
+        self.visibility.is_visible(expert)
+    }
+
+    /// Values displayed on the consecutive screens of the page.
+    pub fn values(&self) -> &[LedgerValue] {
+        &self.values
+    }
+
+    /// Whether the value had characters that Ledger can't display, escaped in `values`.
+    pub fn is_sanitized(&self) -> bool {
+        self.sanitized
+    }
+
+    // Label of the page on the screen with the value number `idx`.
+    fn title(&self, idx: usize) -> String {
         let total_count = self.values.len();
         if total_count == 1 {
             // The whole value can fit on one screen.
-            return vec![format!("{} : {}", self.name, self.values[0])];
+            return self.name.clone();
         }
-        let mut output = vec![];
-        // Split value display into multiple screens.
-        for (idx, value) in self.values.iter().enumerate() {
-            output.push(format!(
-                "{} [{}/{}] : {}",
-                self.name,
-                idx + 1, // Start with 1, not 0.
-                total_count,
-                value.into_str()
-            ));
-        }
-        output
+        // Start with 1, not 0.
+        format!("{} [{}/{}]", self.name, idx + 1, total_count)
+    }
+
+    /// Turn the current element into printable Ledger views.
+    /// Adds indexes and labels.
+    pub fn to_string(&self) -> Vec<String> {
+        self.values
+            .iter()
+            .enumerate()
+            .map(|(idx, value)| format!("{} : {}", self.title(idx), value.into_str()))
+            .collect()
+    }
+
+    /// Screens the page is displayed on.
+    pub fn screens(&self) -> Vec<Screen> {
+        self.values
+            .iter()
+            .enumerate()
+            .map(|(idx, value)| Screen::new(self.title(idx), value.rows.clone()))
+            .collect()
     }
 }
 
-///
-struct LedgerView {
+/// Ledger representation of the whole transaction.
+pub struct LedgerView {
     pages: Vec<LedgerPageView>,
 }
 
 impl LedgerView {
-    fn from_ledger(ledger: Ledger) -> Self {
-        let pages = ledger
-            .into_ledger_elements()
-            .map(LedgerPageView::from_element)
-            .collect();
-        LedgerView { pages }
+    /// Parses the deploy and lays its elements out on the pages of the device's display.
+    pub fn from_deploy(
+        deploy: Deploy,
+        config: &ParserConfig,
+        device: &DeviceProfile,
+    ) -> Result<Self, Error> {
+        Self::from_elements(parser::parse_deploy(deploy, config)?, device)
     }
 
-    // Builds a vector of strings that follows the pattern:
-    // "0 | Type : Transfer",
-    // "1 | To [1/2] : 0101010101010101010101010101010101",
-    // "1 | To [2/2] : 010101010101010101010101010101",
-    // "2 | Amount : CSPR 24.5",
-    // "3 | Id : 999",
-    // "4 | Payment : "CSPR 1"
-    fn to_string(&self, expert: bool) -> Vec<String> {
-        let mut output = vec![];
-        for (idx, page) in self
-            .pages
+    fn from_ledger(ledger: Ledger, device: &DeviceProfile) -> Result<Self, Error> {
+        Self::from_elements(ledger.into_ledger_elements(), device)
+    }
+
+    /// Lays the elements out on the pages of the device's display.
+    pub fn from_elements<I: IntoIterator<Item = Element>>(
+        elements: I,
+        device: &DeviceProfile,
+    ) -> Result<Self, Error> {
+        let pages = elements
+            .into_iter()
+            .map(|element| LedgerPageView::from_element(element, device))
+            .collect::<Result<_, _>>()?;
+        Ok(LedgerView { pages })
+    }
+
+    /// Pages displayed in the chosen mode.
+    pub fn visible_pages(&self, expert: bool) -> impl Iterator<Item = &LedgerPageView> {
+        self.pages
             .iter()
-            .filter(|page| if !page.expert { true } else { expert })
-            .enumerate()
-        {
+            .filter(move |page| page.is_visible(expert))
+    }
+
+    /// Whether any of the pages displayed in the chosen mode had characters that Ledger can't display.
+    pub fn is_sanitized(&self, expert: bool) -> bool {
+        self.visible_pages(expert).any(LedgerPageView::is_sanitized)
+    }
+
+    /// Number of elements displayed in the chosen mode.
+    /// Every element is counted once, even if its value spans multiple screens.
+    pub fn element_count(&self, expert: bool) -> usize {
+        self.visible_pages(expert).count()
+    }
+
+    /// Screens displayed in the chosen mode, in the order they are displayed in.
+    pub fn screens(&self, expert: bool) -> Vec<Screen> {
+        self.visible_pages(expert)
+            .flat_map(LedgerPageView::screens)
+            .collect()
+    }
+
+    /// Builds a vector of strings that follows the pattern:
+    /// ```text
+    /// "0 | Type : Transfer",
+    /// "1 | To [1/2] : 0101010101010101010101010101010101",
+    /// "1 | To [2/2] : 010101010101010101010101010101",
+    /// "2 | Amount : CSPR 24.5",
+    /// "3 | Id : 999",
+    /// "4 | Payment : "CSPR 1"
+    /// ```
+    pub fn to_string(&self, expert: bool) -> Vec<String> {
+        let mut output = vec![];
+        for (idx, page) in self.visible_pages(expert).enumerate() {
             let pages_str: Vec<String> = page
                 .to_string()
                 .into_iter()
@@ -248,88 +399,365 @@ impl LedgerView {
     }
 }
 
-#[derive(Clone)]
-#[allow(unused)]
+// Labels of the elements that are always displayed, even if the transaction exceeds the element limit.
+const SUMMARY_LABELS: [&str; 5] = ["Txn hash", "Type", "Chain ID", "Account", "Fee"];
+// Labels of the (expert) elements describing the deploy's header.
+const HEADER_LABELS: [&str; 5] = ["Timestamp", "Ttl", "Gas price", "Deps #", "Approvals #"];
+
+type OnLimitExceeded = Rc<dyn Fn(&Ledger) -> Vec<Element>>;
 
-pub(crate) struct LimitedLedgerConfig {
-    page_limit: u8,
-    on_regular: Rc<dyn Fn(&Ledger) -> Vec<String>>,
-    on_expert: Rc<dyn Fn(&Ledger) -> Vec<String>>,
+/// Configuration of the Ledger representation of a transaction that is limited in the number of elements
+/// (numbered entries of the output, each displayed on one or more screens).
+/// When the transaction has more than `element_limit` elements, its representation is replaced with the output
+/// of `on_regular` (in regular mode) or `on_expert` (in expert mode).
+/// Pages are laid out for the display of the `device`, from the elements parsed according to `parser`.
+#[derive(Clone)]
+pub struct LimitedLedgerConfig {
+    element_limit: u8,
+    device: DeviceProfile,
+    parser: ParserConfig,
+    on_regular: OnLimitExceeded,
+    on_expert: OnLimitExceeded,
 }
 
 impl LimitedLedgerConfig {
-    pub(crate) fn new(page_limit: u8) -> Self {
+    pub fn new(element_limit: u8) -> Self {
         Self {
-            page_limit,
+            element_limit,
+            device: DeviceProfile::default(),
+            parser: ParserConfig::default(),
             on_regular: Rc::new(Self::deploy_complexity_notice),
             on_expert: Rc::new(Self::deploy_basic_info),
         }
     }
 
-    fn deploy_complexity_notice(_ledger: &Ledger) -> Vec<String> {
-        todo!()
+    /// Lays out the pages for the display of the given device.
+    pub fn with_device(mut self, device: DeviceProfile) -> Self {
+        self.device = device;
+        self
+    }
+
+    /// Parses the deploys according to the given configuration.
+    pub fn with_parser_config(mut self, parser: ParserConfig) -> Self {
+        self.parser = parser;
+        self
+    }
+
+    /// Configuration the deploys are parsed with.
+    pub fn parser_config(&self) -> &ParserConfig {
+        &self.parser
+    }
+
+    /// Device the pages are laid out for.
+    pub fn device(&self) -> &DeviceProfile {
+        &self.device
     }
 
-    fn deploy_basic_info(_ledger: &Ledger) -> Vec<String> {
-        todo!()
+    /// Maximum number of elements of a transaction, however many screens each of them spans.
+    pub fn element_limit(&self) -> u8 {
+        self.element_limit
+    }
+
+    /// Summary of the deploy with a notice asking user to switch to expert mode.
+    fn deploy_complexity_notice(ledger: &Ledger) -> Vec<Element> {
+        let mut elements = ledger.elements_labeled(&SUMMARY_LABELS);
+        elements.push(Element::regular(
+            "Notice",
+            "Review txn in expert mode".to_string(),
+        ));
+        elements
+    }
+
+    /// Summary of the deploy and its header with a notice asking user to verify the deploy's hash.
+    fn deploy_basic_info(ledger: &Ledger) -> Vec<Element> {
+        let labels: Vec<&str> = SUMMARY_LABELS
+            .iter()
+            .chain(&HEADER_LABELS)
+            .cloned()
+            .collect();
+        let mut elements = ledger.elements_labeled(&labels);
+        elements.push(Element::regular(
+            "Notice",
+            "Txn too long, verify txn hash".to_string(),
+        ));
+        elements
     }
 }
 
 struct LimitedLedgerView<'a> {
-    _config: &'a LimitedLedgerConfig,
+    config: &'a LimitedLedgerConfig,
     ledger: Ledger,
 }
 
 impl<'a> LimitedLedgerView<'a> {
     fn new(config: &'a LimitedLedgerConfig, ledger: Ledger) -> Self {
-        Self {
-            _config: config,
-            ledger,
-        }
+        Self { config, ledger }
     }
 
-    fn regular(&self) -> Vec<String> {
-        LedgerView::from_ledger(self.ledger.clone()).to_string(false)
+    /// Returns the regular mode representation.
+    fn regular(&self) -> Result<LimitedOutput, Error> {
+        self.limited(false, &self.config.on_regular)
     }
 
-    fn expert(&self) -> Vec<String> {
-        LedgerView::from_ledger(self.ledger.clone()).to_string(true)
+    /// Returns the expert mode representation.
+    fn expert(&self) -> Result<LimitedOutput, Error> {
+        self.limited(true, &self.config.on_expert)
+    }
+
+    fn limited(
+        &self,
+        expert: bool,
+        on_limit_exceeded: &OnLimitExceeded,
+    ) -> Result<LimitedOutput, Error> {
+        let (view, limit_exceeded) = self.limited_view(expert, on_limit_exceeded)?;
+        Ok(LimitedOutput::new(&view, expert, limit_exceeded))
+    }
+
+    /// Returns the view of the transaction in the chosen mode and whether it exceeded the element limit.
+    fn limited_view(
+        &self,
+        expert: bool,
+        on_limit_exceeded: &OnLimitExceeded,
+    ) -> Result<(LedgerView, bool), Error> {
+        let device = &self.config.device;
+        let view = LedgerView::from_ledger(self.ledger.clone(), device)?;
+        if view.element_count(expert) <= self.config.element_limit as usize {
+            return Ok((view, false));
+        }
+        let limited_view = LedgerView::from_elements(on_limit_exceeded(&self.ledger), device)?;
+        Ok((limited_view, true))
+    }
+}
+
+/// Representation of the transaction in one of the modes.
+#[derive(Default)]
+struct LimitedOutput {
+    pages: Vec<String>,
+    // Whether the pages were replaced because the transaction exceeded the element limit.
+    limit_exceeded: bool,
+    // Whether any of the displayed values had characters that Ledger can't display.
+    sanitized: bool,
+}
+
+impl LimitedOutput {
+    fn new(view: &LedgerView, expert: bool, limit_exceeded: bool) -> Self {
+        LimitedOutput {
+            pages: view.to_string(expert),
+            limit_exceeded,
+            sanitized: view.is_sanitized(expert),
+        }
     }
 }
 
 /// Representation of a test vector that is structures in the way that Zondax's pipelines expect it.
-#[derive(Serialize, Deserialize)]
-pub(super) struct ZondaxRepr {
-    index: usize,
-    name: String,
-    valid_regular: bool,
-    valid_expert: bool,
-    testnet: bool,
-    blob: String,
-    output: Vec<String>,
-    output_expert: Vec<String>,
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ZondaxRepr {
+    pub index: usize,
+    pub name: String,
+    pub valid_regular: bool,
+    pub valid_expert: bool,
+    pub testnet: bool,
+    pub blob: String,
+    pub output: Vec<String>,
+    pub output_expert: Vec<String>,
+    /// Whether `output` was replaced because the transaction exceeded the element limit.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub limit_exceeded_regular: bool,
+    /// Whether `output_expert` was replaced because the transaction exceeded the element limit.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub limit_exceeded_expert: bool,
+    /// Whether any of the displayed values had characters that Ledger can't display, escaped in the outputs.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub sanitized: bool,
+    /// Why Ledger can't display the transaction. Such test vectors are invalid and have no output.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// APDUs sending `blob` to the app for signing, when requested (see [`ZondaxRepr::with_apdus`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub apdus: Option<Vec<Apdu>>,
+    /// Hex-encoded signature of the deploy's hash expected from a test device, added to the test vectors
+    /// valid in any of the modes when requested (see [`ZondaxRepr::with_signature`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+}
+
+impl ZondaxRepr {
+    /// Whether the transaction can be signed in both modes.
+    pub fn is_valid(&self) -> bool {
+        self.valid_regular && self.valid_expert
+    }
+
+    /// Adds the APDUs sending the transaction to the app for signing.
+    ///
+    /// Fails if `blob` isn't hex-encoded, which can happen only for a test vector loaded from a file.
+    pub fn with_apdus(mut self) -> Result<Self, Error> {
+        let blob = hex::decode(&self.blob)
+            .map_err(|err| Error::InvalidBlob(format!("invalid hex: {}", err)))?;
+        self.apdus = Some(apdu::sign_apdus(&blob));
+        Ok(self)
+    }
+
+    /// Adds the signature of the deploy's hash made with the test device's key, if the device can sign the
+    /// transaction in any of the modes.
+    ///
+    /// Fails if `blob` isn't a serialized deploy, which can happen only for a test vector loaded from a file.
+    pub fn with_signature(mut self, signer: &TestSigner) -> Result<Self, Error> {
+        if self.valid_regular || self.valid_expert {
+            let deploy = deploy_from_blob(&self.blob)?;
+            self.signature = Some(signer.sign_to_hex(deploy.id()));
+        }
+        Ok(self)
+    }
+}
+
+// Regular and expert mode representations of the deploy.
+fn limited_outputs(
+    deploy: Deploy,
+    config: &LimitedLedgerConfig,
+) -> Result<(LimitedOutput, LimitedOutput), Error> {
+    let ledger = Ledger::from_deploy(deploy, &config.parser)?;
+    let ledger_view = LimitedLedgerView::new(config, ledger);
+    Ok((ledger_view.regular()?, ledger_view.expert()?))
+}
+
+/// Returns the regular and expert mode views of the deploy, as displayed in the test vector's outputs -
+/// replaced when the deploy exceeds the element limit.
+pub fn limited_views(
+    deploy: Deploy,
+    config: &LimitedLedgerConfig,
+) -> Result<(LedgerView, LedgerView), Error> {
+    let ledger = Ledger::from_deploy(deploy, &config.parser)?;
+    let ledger_view = LimitedLedgerView::new(config, ledger);
+    let (regular, _) = ledger_view.limited_view(false, &config.on_regular)?;
+    let (expert, _) = ledger_view.limited_view(true, &config.on_expert)?;
+    Ok((regular, expert))
 }
 
 /// Maps `Deploy` structure to the expected JSON representation.
-pub(super) fn deploy_to_json(
+///
+/// A deploy that Ledger can't display is mapped to an invalid test vector, with the reason in its `error` field.
+/// Fails only if the deploy can't be serialized into the `blob`.
+pub fn deploy_to_json(
     index: usize,
     sample_deploy: Sample<Deploy>,
     config: &LimitedLedgerConfig,
-) -> ZondaxRepr {
+) -> Result<ZondaxRepr, Error> {
     let (name, deploy, valid) = sample_deploy.destructure();
-    let blob = hex::encode(&deploy.to_bytes().unwrap());
-    let ledger = Ledger::from_deploy(deploy);
-    let ledger_view = LimitedLedgerView::new(config, ledger);
-    let output = ledger_view.regular();
-    let output_expert = ledger_view.expert();
-    ZondaxRepr {
+    let bytes = deploy
+        .to_bytes()
+        .map_err(|err| Error::Serialization(err.to_string()))?;
+    let blob = hex::encode(&bytes);
+    let testnet = Network::from_chain_name(deploy.header().chain_name()).is_testnet();
+    let (regular, expert, error) = match limited_outputs(deploy, config) {
+        Ok((regular, expert)) => (regular, expert, None),
+        Err(error) => (
+            LimitedOutput::default(),
+            LimitedOutput::default(),
+            Some(error.to_string()),
+        ),
+    };
+    let displayable = error.is_none();
+    Ok(ZondaxRepr {
         index,
         name,
-        valid_regular: valid,
-        valid_expert: valid,
-        testnet: true,
+        // Regular mode can't sign a deploy exceeding the element limit, it asks to review it in expert mode.
+        valid_regular: valid.regular && displayable && !regular.limit_exceeded,
+        valid_expert: valid.expert && displayable,
+        testnet,
         blob,
-        output,
-        output_expert,
+        output: regular.pages,
+        output_expert: expert.pages,
+        limit_exceeded_regular: regular.limit_exceeded,
+        limit_exceeded_expert: expert.limit_exceeded,
+        sanitized: regular.sanitized || expert.sanitized,
+        error,
+        apdus: None,
+        signature: None,
+    })
+}
+
+/// Maps the indexed samples to test vectors (see [`deploy_to_json`]).
+pub fn deploys_to_json<I>(
+    samples: I,
+    config: &LimitedLedgerConfig,
+) -> Result<Vec<ZondaxRepr>, Error>
+where
+    I: IntoIterator<Item = (usize, Sample<Deploy>)>,
+{
+    samples
+        .into_iter()
+        .map(|(index, sample_deploy)| deploy_to_json(index, sample_deploy, config))
+        .collect()
+}
+
+/// Decodes the `blob` of a test vector (hex of `Deploy::to_bytes`) back into a `Deploy`.
+pub fn deploy_from_blob(blob: &str) -> Result<Deploy, Error> {
+    let bytes = hex::decode(blob.trim())
+        .map_err(|err| Error::InvalidBlob(format!("invalid hex: {}", err)))?;
+    bytesrepr::deserialize(bytes)
+        .map_err(|err| Error::InvalidBlob(format!("invalid deploy bytes: {}", err)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        deploy_from_blob, deploy_to_json, DeviceProfile, Element, LedgerPageView,
+        LimitedLedgerConfig,
+    };
+    use crate::{
+        error::Error,
+        test_data::{redelegate_samples, SampleFilter},
+        Network, TestRng,
+    };
+
+    #[test]
+    fn long_label_is_an_error() {
+        let element = Element::regular("Validator public key", "01".to_string());
+        let result = LedgerPageView::from_element(element, &DeviceProfile::NANO_S);
+        assert_eq!(
+            Some(Error::LabelTooLong {
+                label: "Validator public key".to_string(),
+                max_chars: 11,
+            }),
+            result.err()
+        );
+    }
+
+    #[test]
+    fn invalid_blob_is_an_error() {
+        assert!(matches!(deploy_from_blob("0g"), Err(Error::InvalidBlob(_))));
+        assert!(matches!(
+            deploy_from_blob("0102"),
+            Err(Error::InvalidBlob(_))
+        ));
+    }
+
+    #[test]
+    fn element_limit_replaces_output() {
+        let mut rng = TestRng::new();
+        let sample = redelegate_samples(&mut rng, &Network::default()).remove(0);
+
+        let unlimited =
+            deploy_to_json(0, sample.clone(), &LimitedLedgerConfig::new(u8::MAX)).unwrap();
+        assert!(!unlimited.limit_exceeded_regular);
+        assert!(!unlimited.limit_exceeded_expert);
+
+        let limited = deploy_to_json(0, sample, &LimitedLedgerConfig::new(1)).unwrap();
+        assert!(limited.limit_exceeded_regular);
+        assert!(limited.limit_exceeded_expert);
+        assert!(!limited.valid_regular);
+        assert!(limited.valid_expert);
+        // The sample is valid, but its test vector isn't.
+        let only_valid = SampleFilter {
+            valid: Some(true),
+            ..SampleFilter::default()
+        };
+        assert!(only_valid.matches_vector(&unlimited));
+        assert!(!only_valid.matches_vector(&limited));
+        assert_eq!(
+            "5 | Notice : Review txn in expert mode",
+            limited.output.last().unwrap()
+        );
     }
 }
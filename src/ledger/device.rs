@@ -0,0 +1,93 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Display geometry of a Ledger device model.
+///
+/// Ledger displays every page as a label row followed by `value_rows` rows of the value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeviceProfile {
+    /// Model name, as used by Zemu.
+    pub name: &'static str,
+    /// Character limit for Ledger's "label" row.
+    pub label_chars: usize,
+    /// Character limit for a single row of the value.
+    pub value_row_chars: usize,
+    /// Number of rows the value is displayed on.
+    pub value_rows: usize,
+}
+
+impl DeviceProfile {
+    pub const NANO_S: DeviceProfile = DeviceProfile {
+        name: "nanos",
+        label_chars: 11,
+        value_row_chars: 17,
+        value_rows: 2,
+    };
+
+    pub const NANO_X: DeviceProfile = DeviceProfile {
+        name: "nanox",
+        label_chars: 20,
+        value_row_chars: 17,
+        value_rows: 4,
+    };
+
+    pub const NANO_S_PLUS: DeviceProfile = DeviceProfile {
+        name: "nanosp",
+        label_chars: 20,
+        value_row_chars: 17,
+        value_rows: 4,
+    };
+
+    pub const STAX: DeviceProfile = DeviceProfile {
+        name: "stax",
+        label_chars: 30,
+        value_row_chars: 28,
+        value_rows: 8,
+    };
+
+    pub const FLEX: DeviceProfile = DeviceProfile {
+        name: "flex",
+        label_chars: 30,
+        value_row_chars: 26,
+        value_rows: 7,
+    };
+
+    /// All the supported device models.
+    pub const ALL: [DeviceProfile; 5] = [
+        Self::NANO_S,
+        Self::NANO_X,
+        Self::NANO_S_PLUS,
+        Self::STAX,
+        Self::FLEX,
+    ];
+}
+
+impl Default for DeviceProfile {
+    fn default() -> Self {
+        Self::NANO_S
+    }
+}
+
+impl Display for DeviceProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl FromStr for DeviceProfile {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|device| device.name == name)
+            .cloned()
+            .ok_or_else(|| {
+                let names: Vec<&str> = Self::ALL.iter().map(|device| device.name).collect();
+                format!(
+                    "unknown device '{}', expected one of: {}",
+                    name,
+                    names.join(", ")
+                )
+            })
+    }
+}
@@ -0,0 +1,45 @@
+use std::borrow::Cow;
+
+// Ledger's font supports printable ASCII only. The backslash is reserved for escaping.
+fn is_displayable(c: char) -> bool {
+    matches!(c, ' '..='~') && c != '\\'
+}
+
+/// Escapes the characters that Ledger can't display.
+///
+/// Every character other than printable ASCII (`0x20`-`0x7e`) is escaped as `\u{XXXX}` - its lowercase hex
+/// code point, without leading zeros (e.g. a zero-width space becomes `\u{200b}`, a newline `\u{a}`).
+/// A backslash is escaped as `\\`, so that escaped values can't be confused with the original ones.
+///
+/// Returns the value unchanged (borrowed) when all of its characters can be displayed.
+pub fn sanitize(value: &str) -> Cow<'_, str> {
+    if value.chars().all(is_displayable) {
+        return Cow::Borrowed(value);
+    }
+    let mut sanitized = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => sanitized.push_str("\\\\"),
+            c if is_displayable(c) => sanitized.push(c),
+            c => sanitized.push_str(&format!("\\u{{{:x}}}", c as u32)),
+        }
+    }
+    Cow::Owned(sanitized)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::sanitize;
+
+    #[test]
+    fn escapes_non_printable_ascii() {
+        assert_eq!(Cow::Borrowed("by-name ~!"), sanitize("by-name ~!"));
+        assert_eq!("caf\\u{e9}", sanitize("café"));
+        assert_eq!("zero\\u{200b}width", sanitize("zero\u{200b}width"));
+        assert_eq!("new\\u{a}line\\u{7f}", sanitize("new\nline\u{7f}"));
+        assert_eq!("launch_\\u{1f680}", sanitize("launch_🚀"));
+        assert_eq!("back\\\\slash", sanitize("back\\slash"));
+    }
+}
@@ -0,0 +1,124 @@
+use super::DeviceProfile;
+
+// Size of a character in the SVG snapshots, in pixels.
+const SVG_CHAR_WIDTH: usize = 10;
+const SVG_ROW_HEIGHT: usize = 20;
+// Margin around the text in the SVG snapshots, in pixels.
+const SVG_MARGIN: usize = 10;
+
+/// Single screen of the device: the title row (label of the page, with the screen's number
+/// when the page spans multiple screens) and the rows of the value displayed on it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Screen {
+    title: String,
+    rows: Vec<String>,
+}
+
+impl Screen {
+    pub(crate) fn new(title: String, rows: Vec<String>) -> Self {
+        Screen { title, rows }
+    }
+
+    /// Title row of the screen, e.g. `Account [1/2]`.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Rows of the value displayed on the screen.
+    pub fn rows(&self) -> &[String] {
+        &self.rows
+    }
+
+    // Title and all the value rows of the device, the unused ones empty.
+    fn lines(&self, device: &DeviceProfile) -> Vec<&str> {
+        let mut lines = vec![self.title.as_str()];
+        lines.extend(self.rows.iter().map(String::as_str));
+        lines.resize(device.value_rows + 1, "");
+        lines
+    }
+
+    // Width of the screen in characters: the device's rows, widened for titles that don't fit in them.
+    fn width(&self, device: &DeviceProfile) -> usize {
+        self.lines(device)
+            .iter()
+            .map(|line| line.chars().count())
+            .chain([device.label_chars, device.value_row_chars])
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Draws the screen as an ASCII-art frame, with all the lines centered like on the device:
+    /// ```text
+    /// +-----------------+
+    /// |  Account [1/2]  |
+    /// |0202531Fe6068134e|
+    /// |bE6C8De7EFF3fC00A|
+    /// +-----------------+
+    /// ```
+    pub fn to_ascii(&self, device: &DeviceProfile) -> String {
+        let width = self.width(device);
+        let border = format!("+{}+", "-".repeat(width));
+        let mut frame = vec![border.clone()];
+        frame.extend(
+            self.lines(device)
+                .into_iter()
+                .map(|line| format!("|{:^width$}|", line, width = width)),
+        );
+        frame.push(border);
+        frame.join("\n") + "\n"
+    }
+
+    /// Draws the screen as an SVG image: white monospace text, centered, on a black background.
+    pub fn to_svg(&self, device: &DeviceProfile) -> String {
+        let lines = self.lines(device);
+        let width = self.width(device) * SVG_CHAR_WIDTH + 2 * SVG_MARGIN;
+        let height = lines.len() * SVG_ROW_HEIGHT + 2 * SVG_MARGIN;
+        let mut svg = vec![
+            format!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#,
+                width, height
+            ),
+            r#"<rect width="100%" height="100%" fill="black"/>"#.to_string(),
+        ];
+        for (idx, line) in lines.iter().enumerate() {
+            // Text is positioned by its baseline.
+            let y = SVG_MARGIN + (idx + 1) * SVG_ROW_HEIGHT - SVG_ROW_HEIGHT / 4;
+            svg.push(format!(
+                r#"<text x="{}" y="{}" fill="white" font-family="monospace" font-size="16" text-anchor="middle" xml:space="preserve">{}</text>"#,
+                width / 2,
+                y,
+                escape_xml(line)
+            ));
+        }
+        svg.push("</svg>".to_string());
+        svg.join("\n") + "\n"
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Screen;
+    use crate::ledger::DeviceProfile;
+
+    #[test]
+    fn ascii_frame() {
+        let screen = Screen::new(
+            "Account [1/2]".to_string(),
+            vec!["0202531Fe6068134e".to_string()],
+        );
+        let expected = "\
++-----------------+
+|  Account [1/2]  |
+|0202531Fe6068134e|
+|                 |
++-----------------+
+";
+        assert_eq!(expected, screen.to_ascii(&DeviceProfile::NANO_S));
+    }
+}
@@ -0,0 +1,103 @@
+/// Kind of the element's value, deciding where it can be broken into the rows of the device's display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueKind {
+    /// Hashes, keys and other hex strings. Split at any character.
+    Hex,
+    /// Numbers with space-separated digit groups, like amounts. Split between the groups.
+    Numeric,
+    /// Human-readable text, like names and entry points. Split after spaces and separators.
+    Text,
+}
+
+// Characters, other than whitespace, that text can be split after.
+const TEXT_SEPARATORS: [char; 6] = ['-', '_', '.', '/', ':', ','];
+
+impl Default for ValueKind {
+    fn default() -> Self {
+        ValueKind::Text
+    }
+}
+
+impl ValueKind {
+    // Whether a row can end right after the character.
+    fn can_break_after(&self, c: char) -> bool {
+        match self {
+            ValueKind::Hex => false,
+            ValueKind::Numeric => c == ' ',
+            ValueKind::Text => c.is_whitespace() || TEXT_SEPARATORS.contains(&c),
+        }
+    }
+
+    /// Splits the value into rows of at most `row_chars` characters.
+    ///
+    /// Words (including the space or separator that ends them) are never split, unless they don't fit
+    /// in a row on their own - then they are split at `row_chars`, like hex values.
+    /// Concatenated rows are always equal to the value.
+    pub fn wrap(&self, value: &str, row_chars: usize) -> Vec<String> {
+        let mut rows = vec![];
+        let mut row = String::new();
+        let mut row_len = 0;
+        for word in self.words(value) {
+            let word_len = word.chars().count();
+            if row_len > 0 && row_len + word_len > row_chars {
+                rows.push(std::mem::take(&mut row));
+                row_len = 0;
+            }
+            for c in word.chars() {
+                if row_len == row_chars {
+                    rows.push(std::mem::take(&mut row));
+                    row_len = 0;
+                }
+                row.push(c);
+                row_len += 1;
+            }
+        }
+        if row_len > 0 {
+            rows.push(row);
+        }
+        rows
+    }
+
+    // Splits the value after every character that a row can end with.
+    fn words(&self, value: &str) -> Vec<String> {
+        let mut words = vec![];
+        let mut word = String::new();
+        for c in value.chars() {
+            word.push(c);
+            if self.can_break_after(c) {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+        if !word.is_empty() {
+            words.push(word);
+        }
+        words
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ValueKind;
+
+    #[test]
+    fn wrap() {
+        assert_eq!(
+            vec!["01234567", "89abcdef", "0123"],
+            ValueKind::Hex.wrap("0123456789abcdef0123", 8)
+        );
+        assert_eq!(
+            vec!["1 000 000 000 ", "motes"],
+            ValueKind::Numeric.wrap("1 000 000 000 motes", 17)
+        );
+        assert_eq!(
+            vec!["transfer_", "from-", "account"],
+            ValueKind::Text.wrap("transfer_from-account", 10)
+        );
+        // Words longer than a row are split like hex values.
+        assert_eq!(
+            vec!["by ", "contractnam", "e"],
+            ValueKind::Text.wrap("by contractname", 11)
+        );
+        assert!(ValueKind::Text.wrap("", 11).is_empty());
+    }
+}
@@ -0,0 +1,30 @@
+//! Test vectors for Ledger hardware integration with CasperNetwork.
+//!
+//! The library exposes the whole pipeline that turns a transaction into its Ledger representation:
+//! * [`parser::parse_deploy`] maps a `Deploy` to a series of [`ledger::Element`]s,
+//! * [`ledger::LedgerView`] lays the elements out on the pages of the device's display,
+//!   for both regular and expert modes,
+//! * [`ledger::deploy_to_json`] builds the test vector ([`ledger::ZondaxRepr`]) in the format expected by Zondax,
+//! * [`vectors::load`] reads a test vectors file back, checking that it's consistent.
+//!
+//! Deploys that Ledger can't display make the pipeline return an [`Error`] instead of panicking.
+//!
+//! Wallets can use it to display the same fields and page splits that the Ledger app shows.
+
+pub mod apdu;
+pub mod checksummed_hex;
+pub mod error;
+pub mod ledger;
+pub mod network;
+pub mod parser;
+pub mod sample;
+pub mod test_data;
+pub mod test_rng;
+pub mod test_signer;
+mod utils;
+pub mod vectors;
+
+pub use error::Error;
+pub use network::Network;
+pub use test_rng::TestRng;
+pub use test_signer::TestSigner;
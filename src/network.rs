@@ -0,0 +1,80 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Casper network the deploys are made for, identified by its chain name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Network {
+    /// The public mainnet.
+    Mainnet,
+    /// The public testnet.
+    Testnet,
+    /// Any other chain, e.g. a private or a local network.
+    Custom(String),
+}
+
+impl Network {
+    pub const MAINNET_CHAIN_NAME: &'static str = "casper";
+    pub const TESTNET_CHAIN_NAME: &'static str = "casper-test";
+
+    pub fn from_chain_name(chain_name: &str) -> Self {
+        match chain_name {
+            Self::MAINNET_CHAIN_NAME => Network::Mainnet,
+            Self::TESTNET_CHAIN_NAME => Network::Testnet,
+            _ => Network::Custom(chain_name.to_string()),
+        }
+    }
+
+    pub fn chain_name(&self) -> &str {
+        match self {
+            Network::Mainnet => Self::MAINNET_CHAIN_NAME,
+            Network::Testnet => Self::TESTNET_CHAIN_NAME,
+            Network::Custom(chain_name) => chain_name,
+        }
+    }
+
+    /// Whether the device treats the transactions as testnet ones, i.e. made for any network but the mainnet.
+    pub fn is_testnet(&self) -> bool {
+        !matches!(self, Network::Mainnet)
+    }
+}
+
+impl Default for Network {
+    /// The custom `mainnet` chain, which all the samples were made for before the public networks
+    /// were supported. Its system auction isn't known, so its auction samples call the auction
+    /// with `ModuleBytes` sessions only.
+    fn default() -> Self {
+        Network::Custom("mainnet".to_string())
+    }
+}
+
+impl Display for Network {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.chain_name())
+    }
+}
+
+impl FromStr for Network {
+    type Err = String;
+
+    fn from_str(chain_name: &str) -> Result<Self, Self::Err> {
+        if chain_name.is_empty() {
+            return Err("chain name can't be empty".to_string());
+        }
+        Ok(Network::from_chain_name(chain_name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Network;
+
+    #[test]
+    fn only_mainnet_is_not_testnet() {
+        assert_eq!(Network::Mainnet, "casper".parse().unwrap());
+        assert_eq!(Network::Testnet, "casper-test".parse().unwrap());
+        assert_eq!(Network::default(), "mainnet".parse().unwrap());
+        assert!(!Network::Mainnet.is_testnet());
+        assert!(Network::Testnet.is_testnet());
+        assert!(Network::default().is_testnet());
+        assert!("".parse::<Network>().is_err());
+    }
+}
@@ -1,41 +1,81 @@
 mod auction;
+mod cep18;
+mod cep78;
+mod config;
+mod contract_standard;
 mod deploy;
 mod runtime_args;
+mod system_contracts;
+#[cfg(test)]
+mod test_utils;
 mod utils;
 
 use casper_node::types::Deploy;
 
+pub(crate) use cep18::TOKEN_ENTRY_POINTS;
+pub use config::{AmountFormat, ArgsLimits, ParserConfig};
+pub(crate) use system_contracts::{system_auction, SystemAuction};
+
 use crate::{
     checksummed_hex,
-    ledger::{Element, TxnPhase},
+    error::Error,
+    ledger::{Element, TxnPhase, ValueKind},
     parser::deploy::{parse_approvals, parse_deploy_header, parse_phase},
 };
 
-pub(crate) fn parse_deploy(d: Deploy) -> Vec<Element> {
+/// Maps the deploy to a series of elements to be displayed in Ledger.
+///
+/// Fails when the deploy can't be displayed, e.g. when one of its arguments is malformed.
+pub fn parse_deploy(d: Deploy, config: &ParserConfig) -> Result<Vec<Element>, Error> {
     let mut elements = vec![];
-    elements.push(Element::regular(
-        "Txn hash",
-        format!("{}", checksummed_hex::encode(d.id().inner())),
-    ));
-    elements.push(deploy_type(&d));
-    elements.extend(parse_deploy_header(d.header()));
-    elements.extend(parse_phase(d.payment(), TxnPhase::Payment));
-    elements.extend(parse_phase(d.session(), TxnPhase::Session));
+    elements.push(
+        Element::regular(
+            "Txn hash",
+            format!("{}", checksummed_hex::encode(d.id().inner())),
+        )
+        .with_kind(ValueKind::Hex),
+    );
+    elements.push(deploy_type(&d)?);
+    elements.extend(parse_deploy_header(d.header())?);
+    let chain_name = d.header().chain_name();
+    elements.extend(parse_phase(
+        d.payment(),
+        TxnPhase::Payment,
+        chain_name,
+        config,
+    )?);
+    elements.extend(parse_phase(
+        d.session(),
+        TxnPhase::Session,
+        chain_name,
+        config,
+    )?);
     elements.extend(parse_approvals(&d));
-    elements
+    Ok(elements)
 }
 
-fn deploy_type(d: &Deploy) -> Element {
-    let dtype = if auction::is_delegate(d.session()) {
-        "Delegate"
-    } else if auction::is_undelegate(d.session()) {
-        "Undelegate"
-    } else if auction::is_redelegate(d.session()) {
-        "Redelegate"
+fn deploy_type(d: &Deploy) -> Result<Element, Error> {
+    let auction_call = auction::is_system_auction_target(d.session(), d.header().chain_name());
+    let dtype = if auction_call && auction::is_delegate(d.session())? {
+        "Delegate".to_string()
+    } else if auction_call && auction::is_undelegate(d.session())? {
+        "Undelegate".to_string()
+    } else if auction_call && auction::is_redelegate(d.session())? {
+        "Redelegate".to_string()
+    } else if auction_call && auction::is_add_bid(d.session())? {
+        "Add bid".to_string()
+    } else if auction_call && auction::is_withdraw_bid(d.session())? {
+        "Withdraw bid".to_string()
+    } else if auction_call && auction::is_activate_bid(d.session())? {
+        "Activate bid".to_string()
     } else if d.session().is_transfer() {
-        "Token transfer"
+        "Token transfer".to_string()
+    } else if let Some(entry_point) = cep18::token_entry_point(d.session()) {
+        format!("CEP-18 {}", entry_point)
+    } else if let Some(entry_point) = cep78::nft_entry_point(d.session()) {
+        format!("CEP-78 {}", entry_point)
     } else {
-        "Contract execution"
+        "Contract execution".to_string()
     };
-    Element::regular("Type", dtype.to_string())
+    Ok(Element::regular("Type", dtype))
 }
@@ -1,20 +1,24 @@
 use casper_execution_engine::core::engine_state::ExecutableDeployItem;
-use casper_types::{system::mint, RuntimeArgs};
+use casper_types::{system::mint, CLType, RuntimeArgs};
 
 use crate::{
-    ledger::{Element, TxnPhase},
-    parser::deploy::{deploy_type, parse_amount},
+    error::Error,
+    ledger::{Element, TxnPhase, ValueKind},
+    parser::{
+        deploy::{deploy_type, parse_amount},
+        ParserConfig,
+    },
 };
 
-use super::{deploy::identity, runtime_args::parse_optional_arg};
+use super::{runtime_args::parse_optional_arg, system_contracts::system_auction};
 
 fn parse_auction_item<'a, F>(
     method: &str,
     item: &'a ExecutableDeployItem,
     args_parser: F,
-) -> Vec<Element>
+) -> Result<Vec<Element>, Error>
 where
-    F: Fn(&'a RuntimeArgs) -> Vec<Element>,
+    F: Fn(&'a RuntimeArgs) -> Result<Vec<Element>, Error>,
 {
     let mut elements = vec![];
     elements.extend(
@@ -28,118 +32,246 @@ where
     );
     match item {
         ExecutableDeployItem::Transfer { .. } => {
-            panic!("unexpected type for {}", method)
+            return Err(Error::UnexpectedDeployItem {
+                entry_point: method.to_string(),
+            })
         }
         ExecutableDeployItem::StoredContractByHash { args, .. }
         | ExecutableDeployItem::StoredContractByName { args, .. }
         | ExecutableDeployItem::StoredVersionedContractByHash { args, .. }
         | ExecutableDeployItem::StoredVersionedContractByName { args, .. }
         | ExecutableDeployItem::ModuleBytes { args, .. } => {
-            elements.extend(args_parser(args));
+            elements.extend(args_parser(args)?);
         }
     };
-    elements
+    Ok(elements)
 }
 
-pub(crate) fn parse_delegation(item: &ExecutableDeployItem) -> Vec<Element> {
+pub(crate) fn parse_delegation(
+    item: &ExecutableDeployItem,
+    config: &ParserConfig,
+) -> Result<Vec<Element>, Error> {
     let arg_parser = |args| {
         let mut elements = vec![];
         // Public key of the account we're delegating from.
-        elements.extend(parse_delegator(args).into_iter());
+        elements.extend(parse_delegator(args)?.into_iter());
         // Public key of the validator we're delegating to.
-        elements.extend(parse_validator(args).into_iter());
+        elements.extend(parse_validator(args)?.into_iter());
         // Amount we're delegating.
-        elements.extend(parse_amount(args).into_iter());
-        elements
+        elements.extend(parse_amount(args, config)?);
+        Ok(elements)
     };
     parse_auction_item("delegate", item, arg_parser)
 }
 
-pub(crate) fn parse_undelegation(item: &ExecutableDeployItem) -> Vec<Element> {
+pub(crate) fn parse_undelegation(
+    item: &ExecutableDeployItem,
+    config: &ParserConfig,
+) -> Result<Vec<Element>, Error> {
     let arg_parser = |args| {
         let mut elements = vec![];
         // Public key of the account we're delegating from.
-        elements.extend(parse_delegator(args).into_iter());
+        elements.extend(parse_delegator(args)?.into_iter());
         // Public key of the validator we're delegating to.
-        elements.extend(parse_validator(args).into_iter());
+        elements.extend(parse_validator(args)?.into_iter());
         // Amount we're delegating.
-        elements.extend(parse_amount(args).into_iter());
-        elements
+        elements.extend(parse_amount(args, config)?);
+        Ok(elements)
     };
     parse_auction_item("undelegate", item, arg_parser)
 }
 
-pub(crate) fn parse_redelegation(item: &ExecutableDeployItem) -> Vec<Element> {
+pub(crate) fn parse_redelegation(
+    item: &ExecutableDeployItem,
+    config: &ParserConfig,
+) -> Result<Vec<Element>, Error> {
     let arg_parser = |args| {
         let mut elements = vec![];
         // Public key of the account we're delegating from.
-        elements.extend(parse_delegator(args).into_iter());
+        elements.extend(parse_delegator(args)?.into_iter());
         // Public key of the current validator we have been redelagating to so far.
-        elements.extend(parse_old_validator(args).into_iter());
+        elements.extend(parse_old_validator(args)?.into_iter());
         // New validator we're redelegating to.
-        elements.extend(parse_new_validator(args).into_iter());
+        elements.extend(parse_new_validator(args)?.into_iter());
         // Amount we're delegating.
-        elements.extend(parse_amount(args).into_iter());
-        elements
+        elements.extend(parse_amount(args, config)?);
+        Ok(elements)
     };
     parse_auction_item("redelegate", item, arg_parser)
 }
 
+pub(crate) fn parse_add_bid(
+    item: &ExecutableDeployItem,
+    config: &ParserConfig,
+) -> Result<Vec<Element>, Error> {
+    let arg_parser = |args| {
+        let mut elements = vec![];
+        // Public key of the validator bidding for a slot.
+        elements.extend(parse_bidder(args)?.into_iter());
+        // Share of the delegators' rewards the validator takes, in percent.
+        elements.extend(parse_delegation_rate(args)?.into_iter());
+        // Amount the validator bids.
+        elements.extend(parse_amount(args, config)?);
+        Ok(elements)
+    };
+    parse_auction_item("add_bid", item, arg_parser)
+}
+
+pub(crate) fn parse_withdraw_bid(
+    item: &ExecutableDeployItem,
+    config: &ParserConfig,
+) -> Result<Vec<Element>, Error> {
+    let arg_parser = |args| {
+        let mut elements = vec![];
+        // Public key of the validator withdrawing from its bid.
+        elements.extend(parse_bidder(args)?.into_iter());
+        // Amount withdrawn.
+        elements.extend(parse_amount(args, config)?);
+        Ok(elements)
+    };
+    parse_auction_item("withdraw_bid", item, arg_parser)
+}
+
+pub(crate) fn parse_activate_bid(item: &ExecutableDeployItem) -> Result<Vec<Element>, Error> {
+    let arg_parser = |args| {
+        let mut elements = vec![];
+        // Public key of the evicted validator reactivating its bid.
+        elements.extend(parse_validator_public_key(args)?.into_iter());
+        Ok(elements)
+    };
+    parse_auction_item("activate_bid", item, arg_parser)
+}
+
+/// Returns `false` when the deploy item calls a contract other than the system auction of the chain.
+///
+/// Any contract can expose entry points looking like the auction ones, so such calls are displayed
+/// as generic contract calls. The auction contract is known only for the public Casper networks,
+/// on the other chains (e.g. private networks) the calls to stored contracts can't be verified and
+/// are displayed as generic contract calls too. `ModuleBytes` sessions, which call the auction
+/// themselves, are trusted on every chain.
+pub(crate) fn is_system_auction_target(item: &ExecutableDeployItem, chain_name: &str) -> bool {
+    match item {
+        ExecutableDeployItem::ModuleBytes { .. } => true,
+        _ => system_auction(chain_name).map_or(false, |auction| auction.is_called_by(item)),
+    }
+}
+
 /// Returns `true` when the deploy's entry point is *literally* _delegate_
-pub(crate) fn is_delegate(item: &ExecutableDeployItem) -> bool {
-    (is_entrypoint(item, DELEGATE_ENTRYPOINT) || has_delegate_auction_arg(item))
-        && has_delegate_args(item)
+pub(crate) fn is_delegate(item: &ExecutableDeployItem) -> Result<bool, Error> {
+    Ok(
+        (is_entrypoint(item, DELEGATE_ENTRYPOINT) || has_delegate_auction_arg(item)?)
+            && has_delegate_args(item),
+    )
 }
 
 /// Returns `true` when the deploy's entry point is *literally* _undelegate_
-pub(crate) fn is_undelegate(item: &ExecutableDeployItem) -> bool {
-    (is_entrypoint(item, UNDELEGATE_ENTRYPOINT) || has_undelegate_auction_arg(item))
-        && has_undelegate_arg(item)
+pub(crate) fn is_undelegate(item: &ExecutableDeployItem) -> Result<bool, Error> {
+    Ok(
+        (is_entrypoint(item, UNDELEGATE_ENTRYPOINT) || has_undelegate_auction_arg(item)?)
+            && has_undelegate_arg(item),
+    )
 }
 
 /// Returns `true` when the deploy's entry point is *literally* _undelegate_
-pub(crate) fn is_redelegate(item: &ExecutableDeployItem) -> bool {
-    (is_entrypoint(item, REDELEGATE_ENTRYPOINT) || has_redelegate_auction_arg(item))
-        && has_redelegate_arg(item)
+pub(crate) fn is_redelegate(item: &ExecutableDeployItem) -> Result<bool, Error> {
+    Ok(
+        (is_entrypoint(item, REDELEGATE_ENTRYPOINT) || has_redelegate_auction_arg(item)?)
+            && has_redelegate_arg(item),
+    )
+}
+
+/// Returns `true` when the deploy's entry point is *literally* _add_bid_
+pub(crate) fn is_add_bid(item: &ExecutableDeployItem) -> Result<bool, Error> {
+    Ok(
+        (is_entrypoint(item, ADD_BID_ENTRYPOINT) || has_add_bid_auction_arg(item)?)
+            && has_add_bid_args(item),
+    )
+}
+
+/// Returns `true` when the deploy's entry point is *literally* _withdraw_bid_
+pub(crate) fn is_withdraw_bid(item: &ExecutableDeployItem) -> Result<bool, Error> {
+    Ok(
+        (is_entrypoint(item, WITHDRAW_BID_ENTRYPOINT) || has_withdraw_bid_auction_arg(item)?)
+            && has_withdraw_bid_args(item),
+    )
+}
+
+/// Returns `true` when the deploy's entry point is *literally* _activate_bid_
+pub(crate) fn is_activate_bid(item: &ExecutableDeployItem) -> Result<bool, Error> {
+    Ok(
+        (is_entrypoint(item, ACTIVATE_BID_ENTRYPOINT) || has_activate_bid_auction_arg(item)?)
+            && has_activate_bid_args(item),
+    )
 }
 
-fn get_auction_arg(item: &ExecutableDeployItem) -> Option<String> {
+fn get_auction_arg(item: &ExecutableDeployItem) -> Result<Option<String>, Error> {
     match item {
         // ModuleBytes variant does not have an entry point, it defaults to `call()`,
         // so we expect a special named argument called `auction` when detecting auction contract calls.
-        ExecutableDeployItem::ModuleBytes { args, .. } => args.get("auction").map(|cl_value| {
-            cl_value
-                .clone()
-                .into_t::<String>()
-                .expect("argument should be string")
-        }),
-        _ => None,
+        ExecutableDeployItem::ModuleBytes { args, .. } => args
+            .get(AUCTION_ARG_KEY)
+            .map(|cl_value| {
+                cl_value
+                    .clone()
+                    .into_t::<String>()
+                    .map_err(|_| Error::UnexpectedArgType {
+                        name: AUCTION_ARG_KEY.to_string(),
+                        expected: CLType::String,
+                    })
+            })
+            .transpose(),
+        _ => Ok(None),
     }
 }
 
 const DELEGATE_ENTRYPOINT: &str = "delegate";
 const UNDELEGATE_ENTRYPOINT: &str = "undelegate";
 const REDELEGATE_ENTRYPOINT: &str = "redelegate";
+const ADD_BID_ENTRYPOINT: &str = "add_bid";
+const WITHDRAW_BID_ENTRYPOINT: &str = "withdraw_bid";
+const ACTIVATE_BID_ENTRYPOINT: &str = "activate_bid";
+const AUCTION_ARG_KEY: &str = "auction";
 const DELEGATOR_ARG_KEY: &str = "delegator";
 const VALIDATOR_ARG_KEY: &str = "validator";
 const NEW_VALIDATOR_ARG_KEY: &str = "new_validator";
+const PUBLIC_KEY_ARG_KEY: &str = "public_key";
+const DELEGATION_RATE_ARG_KEY: &str = "delegation_rate";
+const VALIDATOR_PUBLIC_KEY_ARG_KEY: &str = "validator_public_key";
 
-fn has_delegate_auction_arg(item: &ExecutableDeployItem) -> bool {
-    get_auction_arg(item)
+fn has_delegate_auction_arg(item: &ExecutableDeployItem) -> Result<bool, Error> {
+    Ok(get_auction_arg(item)?
         .filter(|arg_value| arg_value.to_lowercase() == DELEGATE_ENTRYPOINT)
-        .is_some()
+        .is_some())
 }
 
-fn has_undelegate_auction_arg(item: &ExecutableDeployItem) -> bool {
-    get_auction_arg(item)
+fn has_undelegate_auction_arg(item: &ExecutableDeployItem) -> Result<bool, Error> {
+    Ok(get_auction_arg(item)?
         .filter(|arg_value| arg_value.to_lowercase() == UNDELEGATE_ENTRYPOINT)
-        .is_some()
+        .is_some())
 }
 
-fn has_redelegate_auction_arg(item: &ExecutableDeployItem) -> bool {
-    get_auction_arg(item)
+fn has_redelegate_auction_arg(item: &ExecutableDeployItem) -> Result<bool, Error> {
+    Ok(get_auction_arg(item)?
         .filter(|arg_value| arg_value.to_lowercase() == REDELEGATE_ENTRYPOINT)
-        .is_some()
+        .is_some())
+}
+
+fn has_add_bid_auction_arg(item: &ExecutableDeployItem) -> Result<bool, Error> {
+    Ok(get_auction_arg(item)?
+        .filter(|arg_value| arg_value.to_lowercase() == ADD_BID_ENTRYPOINT)
+        .is_some())
+}
+
+fn has_withdraw_bid_auction_arg(item: &ExecutableDeployItem) -> Result<bool, Error> {
+    Ok(get_auction_arg(item)?
+        .filter(|arg_value| arg_value.to_lowercase() == WITHDRAW_BID_ENTRYPOINT)
+        .is_some())
+}
+
+fn has_activate_bid_auction_arg(item: &ExecutableDeployItem) -> Result<bool, Error> {
+    Ok(get_auction_arg(item)?
+        .filter(|arg_value| arg_value.to_lowercase() == ACTIVATE_BID_ENTRYPOINT)
+        .is_some())
 }
 
 fn has_delegate_args(item: &ExecutableDeployItem) -> bool {
@@ -161,20 +293,88 @@ fn has_redelegate_arg(item: &ExecutableDeployItem) -> bool {
         && item.args().get(mint::ARG_AMOUNT).is_some()
 }
 
-fn parse_delegator(args: &RuntimeArgs) -> Option<Element> {
-    parse_optional_arg(args, DELEGATOR_ARG_KEY, "delegator", false, identity)
+fn has_add_bid_args(item: &ExecutableDeployItem) -> bool {
+    item.args().get(PUBLIC_KEY_ARG_KEY).is_some()
+        && item.args().get(DELEGATION_RATE_ARG_KEY).is_some()
+        && item.args().get(mint::ARG_AMOUNT).is_some()
+}
+
+fn has_withdraw_bid_args(item: &ExecutableDeployItem) -> bool {
+    item.args().get(PUBLIC_KEY_ARG_KEY).is_some() && item.args().get(mint::ARG_AMOUNT).is_some()
+}
+
+fn has_activate_bid_args(item: &ExecutableDeployItem) -> bool {
+    item.args().get(VALIDATOR_PUBLIC_KEY_ARG_KEY).is_some()
+}
+
+fn parse_delegator(args: &RuntimeArgs) -> Result<Option<Element>, Error> {
+    parse_optional_arg(
+        args,
+        DELEGATOR_ARG_KEY,
+        "delegator",
+        false,
+        ValueKind::Hex,
+        Ok,
+    )
 }
 
-fn parse_validator(args: &RuntimeArgs) -> Option<Element> {
-    parse_optional_arg(args, VALIDATOR_ARG_KEY, "validator", false, identity)
+fn parse_validator(args: &RuntimeArgs) -> Result<Option<Element>, Error> {
+    parse_optional_arg(
+        args,
+        VALIDATOR_ARG_KEY,
+        "validator",
+        false,
+        ValueKind::Hex,
+        Ok,
+    )
 }
 
-fn parse_old_validator(args: &RuntimeArgs) -> Option<Element> {
-    parse_optional_arg(args, VALIDATOR_ARG_KEY, "old", false, identity)
+fn parse_old_validator(args: &RuntimeArgs) -> Result<Option<Element>, Error> {
+    parse_optional_arg(args, VALIDATOR_ARG_KEY, "old", false, ValueKind::Hex, Ok)
 }
 
-fn parse_new_validator(args: &RuntimeArgs) -> Option<Element> {
-    parse_optional_arg(args, NEW_VALIDATOR_ARG_KEY, "new", false, identity)
+fn parse_new_validator(args: &RuntimeArgs) -> Result<Option<Element>, Error> {
+    parse_optional_arg(
+        args,
+        NEW_VALIDATOR_ARG_KEY,
+        "new",
+        false,
+        ValueKind::Hex,
+        Ok,
+    )
+}
+
+fn parse_bidder(args: &RuntimeArgs) -> Result<Option<Element>, Error> {
+    parse_optional_arg(
+        args,
+        PUBLIC_KEY_ARG_KEY,
+        "validator",
+        false,
+        ValueKind::Hex,
+        Ok,
+    )
+}
+
+fn parse_delegation_rate(args: &RuntimeArgs) -> Result<Option<Element>, Error> {
+    parse_optional_arg(
+        args,
+        DELEGATION_RATE_ARG_KEY,
+        "deleg. rate",
+        false,
+        ValueKind::Numeric,
+        |rate| Ok(format!("{}%", rate)),
+    )
+}
+
+fn parse_validator_public_key(args: &RuntimeArgs) -> Result<Option<Element>, Error> {
+    parse_optional_arg(
+        args,
+        VALIDATOR_PUBLIC_KEY_ARG_KEY,
+        "validator",
+        false,
+        ValueKind::Hex,
+        Ok,
+    )
 }
 
 fn is_entrypoint(item: &ExecutableDeployItem, expected: &str) -> bool {
@@ -188,3 +388,31 @@ fn is_entrypoint(item: &ExecutableDeployItem, expected: &str) -> bool {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+    use casper_types::{bytesrepr::Bytes, RuntimeArgs};
+
+    use super::is_system_auction_target;
+    use crate::parser::{system_auction, test_utils::call_by_hash};
+
+    #[test]
+    fn unknown_chain_has_no_system_auction() {
+        let mainnet_auction = system_auction("casper").unwrap();
+        let call = call_by_hash(
+            mainnet_auction.contract_hash,
+            "delegate",
+            RuntimeArgs::new(),
+        );
+        assert!(is_system_auction_target(&call, "casper"));
+        assert!(!is_system_auction_target(&call, "casper-test"));
+        assert!(!is_system_auction_target(&call, "my-private-chain"));
+
+        let module_bytes = ExecutableDeployItem::ModuleBytes {
+            module_bytes: Bytes::new(),
+            args: RuntimeArgs::new(),
+        };
+        assert!(is_system_auction_target(&module_bytes, "my-private-chain"));
+    }
+}
@@ -0,0 +1,126 @@
+//! Calls to CEP-18 (ERC-20 style) fungible token contracts.
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{CLType, U256};
+use thousands::Separable;
+
+use crate::{
+    error::Error,
+    ledger::{Element, TxnPhase, ValueKind},
+    parser::deploy::deploy_type,
+    utils::cl_value_to_string,
+};
+
+use super::contract_standard::{called_entry_point, EntryPoint};
+
+const AMOUNT_ARG_KEY: &str = "amount";
+
+/// Entry points of a CEP-18 contract, with the `Key` arguments they take besides the `U256` amount.
+pub(crate) const TOKEN_ENTRY_POINTS: [(&str, &[&str]); 5] = [
+    ("transfer", &["recipient"]),
+    ("approve", &["spender"]),
+    ("transfer_from", &["owner", "recipient"]),
+    ("increase_allowance", &["spender"]),
+    ("decrease_allowance", &["spender"]),
+];
+
+fn entry_points() -> Vec<EntryPoint> {
+    TOKEN_ENTRY_POINTS
+        .iter()
+        .map(|(name, key_args)| {
+            let mut args: Vec<(&str, CLType)> =
+                key_args.iter().map(|name| (*name, CLType::Key)).collect();
+            args.push((AMOUNT_ARG_KEY, CLType::U256));
+            EntryPoint {
+                name: *name,
+                arg_sets: vec![args],
+            }
+        })
+        .collect()
+}
+
+/// Returns the CEP-18 entry point called by the deploy item (see [`called_entry_point`]).
+pub(crate) fn token_entry_point(item: &ExecutableDeployItem) -> Option<&'static str> {
+    called_entry_point(item, &entry_points()).map(|entry_point| entry_point.name)
+}
+
+// `Key` arguments of the CEP-18 entry point.
+fn key_args(entry_point: &str) -> Option<&'static [&'static str]> {
+    TOKEN_ENTRY_POINTS
+        .iter()
+        .find(|(name, _)| *name == entry_point)
+        .map(|(_, key_args)| *key_args)
+}
+
+/// Parses the call to the CEP-18 `entry_point`: the token contract, the `Key` arguments and the amount.
+pub(crate) fn parse_token_call(
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+    entry_point: &str,
+) -> Result<Vec<Element>, Error> {
+    // Which token is called is as important as the amount, so the contract is displayed in regular mode.
+    let mut elements = deploy_type(phase, item);
+    let args = item.args();
+    for name in key_args(entry_point).unwrap_or_default() {
+        if let Some(cl_value) = args.get(name) {
+            let value = cl_value_to_string(cl_value)?;
+            elements.push(Element::regular(name, value).with_kind(ValueKind::Hex));
+        }
+    }
+    if let Some(cl_value) = args.get(AMOUNT_ARG_KEY) {
+        let amount: U256 = cl_value
+            .clone()
+            .into_t()
+            .map_err(|_| Error::UnexpectedArgType {
+                name: AMOUNT_ARG_KEY.to_string(),
+                expected: CLType::U256,
+            })?;
+        elements.push(
+            Element::regular(AMOUNT_ARG_KEY, amount.separate_with_spaces())
+                .with_kind(ValueKind::Numeric),
+        );
+    }
+    Ok(elements)
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U256, U512};
+
+    use super::token_entry_point;
+    use crate::{parser::test_utils::call, test_data::cep18};
+
+    #[test]
+    fn recognises_exact_token_args() {
+        let recipient = Key::Account(AccountHash::new([2; 32]));
+        let args = runtime_args! { "recipient" => recipient, "amount" => U256::from(10u64) };
+        assert_eq!(
+            Some("transfer"),
+            token_entry_point(&call("transfer", args.clone()))
+        );
+        assert_eq!(None, token_entry_point(&call("approve", args)));
+
+        let wrong_type = runtime_args! { "recipient" => recipient, "amount" => U512::from(10u64) };
+        assert_eq!(None, token_entry_point(&call("transfer", wrong_type)));
+
+        let extra_arg = runtime_args! {
+            "recipient" => recipient,
+            "amount" => U256::from(10u64),
+            "memo" => "hidden",
+        };
+        assert_eq!(None, token_entry_point(&call("transfer", extra_arg)));
+    }
+
+    #[test]
+    fn recognises_only_the_valid_token_samples() {
+        for sample in cep18::valid() {
+            let (label, item, _valid) = sample.destructure();
+            let entry_point = label.split("__").next();
+            assert_eq!(entry_point, token_entry_point(&item), "{}", label);
+        }
+        for sample in cep18::invalid() {
+            let (label, item, _valid) = sample.destructure();
+            assert_eq!(None, token_entry_point(&item), "{}", label);
+        }
+    }
+}
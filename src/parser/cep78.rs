@@ -0,0 +1,181 @@
+//! Calls to CEP-78 NFT contracts.
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_hashing::Digest;
+use casper_types::CLType;
+
+use crate::{
+    error::Error,
+    ledger::{Element, TxnPhase, ValueKind},
+    parser::deploy::deploy_type,
+};
+
+use super::{
+    contract_standard::{called_entry_point, EntryPoint},
+    runtime_args::parse_optional_arg,
+};
+
+const TOKEN_OWNER_ARG_KEY: &str = "token_owner";
+const TOKEN_META_DATA_ARG_KEY: &str = "token_meta_data";
+const SOURCE_KEY_ARG_KEY: &str = "source_key";
+const TARGET_KEY_ARG_KEY: &str = "target_key";
+const OPERATOR_ARG_KEY: &str = "operator";
+const APPROVE_ALL_ARG_KEY: &str = "approve_all";
+const TOKEN_ID_ARG_KEY: &str = "token_id";
+const TOKEN_HASH_ARG_KEY: &str = "token_hash";
+
+/// Arguments displayed in regular mode, with their labels, in the order they are displayed in.
+/// Every entry point takes only some of them.
+const DISPLAYED_ARGS: [(&str, &str, ValueKind); 7] = [
+    (TOKEN_OWNER_ARG_KEY, "owner", ValueKind::Hex),
+    (SOURCE_KEY_ARG_KEY, "from", ValueKind::Hex),
+    (TARGET_KEY_ARG_KEY, "recipient", ValueKind::Hex),
+    (OPERATOR_ARG_KEY, "operator", ValueKind::Hex),
+    (APPROVE_ALL_ARG_KEY, "approve all", ValueKind::Text),
+    (TOKEN_ID_ARG_KEY, "token id", ValueKind::Numeric),
+    (TOKEN_HASH_ARG_KEY, "token hash", ValueKind::Hex),
+];
+
+// Sets of the arguments with either `token_id` or `token_hash`, depending on the identifier mode
+// of the contract.
+fn identified(args: Vec<(&'static str, CLType)>) -> Vec<Vec<(&'static str, CLType)>> {
+    let identifiers = vec![
+        (TOKEN_ID_ARG_KEY, CLType::U64),
+        (TOKEN_HASH_ARG_KEY, CLType::String),
+    ];
+    identifiers
+        .into_iter()
+        .map(|identifier| {
+            let mut arg_set = args.clone();
+            arg_set.push(identifier);
+            arg_set
+        })
+        .collect()
+}
+
+fn entry_points() -> Vec<EntryPoint> {
+    let mint = vec![
+        (TOKEN_OWNER_ARG_KEY, CLType::Key),
+        (TOKEN_META_DATA_ARG_KEY, CLType::String),
+    ];
+    // Optional `token_hash` of the minted token, derived from the metadata when missing.
+    let mut mint_with_hash = mint.clone();
+    mint_with_hash.push((TOKEN_HASH_ARG_KEY, CLType::String));
+    vec![
+        EntryPoint {
+            name: "mint",
+            arg_sets: vec![mint, mint_with_hash],
+        },
+        EntryPoint {
+            name: "transfer",
+            arg_sets: identified(vec![
+                (SOURCE_KEY_ARG_KEY, CLType::Key),
+                (TARGET_KEY_ARG_KEY, CLType::Key),
+            ]),
+        },
+        EntryPoint {
+            name: "burn",
+            arg_sets: identified(vec![]),
+        },
+        EntryPoint {
+            name: "approve",
+            arg_sets: identified(vec![(OPERATOR_ARG_KEY, CLType::Key)]),
+        },
+        EntryPoint {
+            name: "set_approval_for_all",
+            arg_sets: vec![vec![
+                (OPERATOR_ARG_KEY, CLType::Key),
+                (APPROVE_ALL_ARG_KEY, CLType::Bool),
+            ]],
+        },
+    ]
+}
+
+/// Returns the CEP-78 entry point called by the deploy item (see [`called_entry_point`]).
+pub(crate) fn nft_entry_point(item: &ExecutableDeployItem) -> Option<&'static str> {
+    called_entry_point(item, &entry_points()).map(|entry_point| entry_point.name)
+}
+
+/// Parses the call to the CEP-78 entry point: the NFT contract, the token's owner, recipient
+/// or operator, the token identifier and, for `mint`, the hash of the metadata.
+pub(crate) fn parse_nft_call(
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+) -> Result<Vec<Element>, Error> {
+    // Which collection the token belongs to is as important as the token itself.
+    let mut elements = deploy_type(phase, item);
+    let args = item.args();
+    for (key, label, kind) in DISPLAYED_ARGS {
+        elements.extend(parse_optional_arg(args, key, label, false, kind, Ok)?);
+    }
+    if let Some(cl_value) = args.get(TOKEN_META_DATA_ARG_KEY) {
+        // Metadata is an arbitrarily long JSON, so only its hash is displayed, for cross-checking
+        // with the wallet - like the args hash of generic transactions.
+        let metadata: String = cl_value
+            .clone()
+            .into_t()
+            .map_err(|_| Error::UnexpectedArgType {
+                name: TOKEN_META_DATA_ARG_KEY.to_string(),
+                expected: CLType::String,
+            })?;
+        let metadata_hash = base16::encode_lower(&Digest::hash(metadata.as_bytes()));
+        elements.push(Element::regular("meta hash", metadata_hash).with_kind(ValueKind::Hex));
+    }
+    Ok(elements)
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{account::AccountHash, runtime_args, Key};
+
+    use super::nft_entry_point;
+    use crate::{parser::test_utils::call, test_data::cep78};
+
+    #[test]
+    fn recognises_both_identifier_modes() {
+        let source = Key::Account(AccountHash::new([2; 32]));
+        let target = Key::Account(AccountHash::new([3; 32]));
+        let by_id = runtime_args! {
+            "source_key" => source,
+            "target_key" => target,
+            "token_id" => 7u64,
+        };
+        assert_eq!(Some("transfer"), nft_entry_point(&call("transfer", by_id)));
+        let by_hash = runtime_args! {
+            "source_key" => source,
+            "target_key" => target,
+            "token_hash" => "a1b2",
+        };
+        assert_eq!(
+            Some("transfer"),
+            nft_entry_point(&call("transfer", by_hash))
+        );
+
+        let both = runtime_args! {
+            "source_key" => source,
+            "target_key" => target,
+            "token_id" => 7u64,
+            "token_hash" => "a1b2",
+        };
+        assert_eq!(None, nft_entry_point(&call("transfer", both)));
+        let missing = runtime_args! { "source_key" => source, "target_key" => target };
+        assert_eq!(None, nft_entry_point(&call("transfer", missing)));
+
+        let mint = runtime_args! { "token_owner" => source, "token_meta_data" => "{}" };
+        assert_eq!(Some("mint"), nft_entry_point(&call("mint", mint)));
+    }
+
+    #[test]
+    fn recognises_only_the_valid_nft_samples() {
+        // Labels start with `nft__<entry point>`.
+        for sample in cep78::valid() {
+            let (label, item, _valid) = sample.destructure();
+            let entry_point = label.split("__").nth(1);
+            assert_eq!(entry_point, nft_entry_point(&item), "{}", label);
+        }
+        for sample in cep78::invalid() {
+            let (label, item, _valid) = sample.destructure();
+            assert_eq!(None, nft_entry_point(&item), "{}", label);
+        }
+    }
+}
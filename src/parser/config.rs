@@ -0,0 +1,118 @@
+use std::{fmt::Display, str::FromStr};
+
+/// How the amounts (fee, transfer and delegation amounts) are displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmountFormat {
+    /// In CSPR, with up to nine decimal places and no trailing zeros, e.g. `1 000.5 CSPR`.
+    Cspr,
+    /// In motes, e.g. `1 000 500 000 000 motes`.
+    Motes,
+    /// In CSPR in regular mode, in motes in expert mode.
+    Both,
+}
+
+impl AmountFormat {
+    /// All the supported formats.
+    pub const ALL: [AmountFormat; 3] =
+        [AmountFormat::Cspr, AmountFormat::Motes, AmountFormat::Both];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AmountFormat::Cspr => "cspr",
+            AmountFormat::Motes => "motes",
+            AmountFormat::Both => "both",
+        }
+    }
+}
+
+impl Default for AmountFormat {
+    fn default() -> Self {
+        AmountFormat::Motes
+    }
+}
+
+impl Display for AmountFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for AmountFormat {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|format| format.name() == name)
+            .cloned()
+            .ok_or_else(|| {
+                let names: Vec<&str> = Self::ALL.iter().map(AmountFormat::name).collect();
+                format!(
+                    "unknown amount format '{}', expected one of: {}",
+                    name,
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+/// Limits of the expert mode listing of runtime arguments.
+///
+/// Arguments exceeding any of them are not listed, only their hash is displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArgsLimits {
+    /// Maximum number of arguments, capped at [`ArgsLimits::MAX_COUNT`].
+    pub max_count: usize,
+    /// Maximum nesting depth of an argument's type, e.g. 0 for `U512` and 2 for `List<Option<U512>>`.
+    pub max_depth: usize,
+    /// Maximum number of characters of an argument's value.
+    pub max_value_chars: usize,
+}
+
+impl ArgsLimits {
+    /// Most arguments that can be listed. The label of the next one, `arg-100-name`, doesn't fit in
+    /// the 11 characters of the Nano S label row.
+    pub const MAX_COUNT: usize = 100;
+}
+
+impl Default for ArgsLimits {
+    fn default() -> Self {
+        ArgsLimits {
+            max_count: 10,
+            max_depth: 2,
+            max_value_chars: 128,
+        }
+    }
+}
+
+/// Configuration of how the deploy is mapped to the elements displayed in Ledger.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParserConfig {
+    amount_format: AmountFormat,
+    args_limits: Option<ArgsLimits>,
+}
+
+impl ParserConfig {
+    /// Displays the amounts in the given format.
+    pub fn with_amount_format(mut self, amount_format: AmountFormat) -> Self {
+        self.amount_format = amount_format;
+        self
+    }
+
+    /// Format the amounts are displayed in.
+    pub fn amount_format(&self) -> AmountFormat {
+        self.amount_format
+    }
+
+    /// Lists the runtime arguments in expert mode, unless they exceed the limits.
+    /// By default only the hash of the arguments is displayed.
+    pub fn with_args_listing(mut self, limits: ArgsLimits) -> Self {
+        self.args_limits = Some(limits);
+        self
+    }
+
+    /// Limits of the runtime arguments listing, if the arguments are listed.
+    pub fn args_limits(&self) -> Option<ArgsLimits> {
+        self.args_limits
+    }
+}
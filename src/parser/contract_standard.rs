@@ -0,0 +1,46 @@
+//! Calls to contracts implementing a standard (CEP-18, CEP-78), recognised by their entry point
+//! and arguments.
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{CLType, RuntimeArgs};
+
+/// Entry point of a contract standard.
+pub(crate) struct EntryPoint {
+    pub(crate) name: &'static str,
+    /// Sets of arguments the entry point accepts, e.g. with either of the token identifiers.
+    pub(crate) arg_sets: Vec<Vec<(&'static str, CLType)>>,
+}
+
+/// Returns the entry point of the standard called by the deploy item.
+///
+/// The item is recognised only if it has exactly the arguments of one of the entry point's sets,
+/// of the expected types - otherwise some of its arguments wouldn't be displayed.
+pub(crate) fn called_entry_point<'a>(
+    item: &ExecutableDeployItem,
+    entry_points: &'a [EntryPoint],
+) -> Option<&'a EntryPoint> {
+    let entry_point = match item {
+        ExecutableDeployItem::ModuleBytes { .. } | ExecutableDeployItem::Transfer { .. } => {
+            return None
+        }
+        ExecutableDeployItem::StoredContractByHash { entry_point, .. }
+        | ExecutableDeployItem::StoredContractByName { entry_point, .. }
+        | ExecutableDeployItem::StoredVersionedContractByHash { entry_point, .. }
+        | ExecutableDeployItem::StoredVersionedContractByName { entry_point, .. } => entry_point,
+    };
+    entry_points.iter().find(|ep| {
+        ep.name == entry_point.as_str()
+            && ep
+                .arg_sets
+                .iter()
+                .any(|arg_set| has_exact_args(item.args(), arg_set))
+    })
+}
+
+fn has_exact_args(args: &RuntimeArgs, expected: &[(&str, CLType)]) -> bool {
+    args.len() == expected.len()
+        && expected.iter().all(|(name, cl_type)| {
+            args.get(name)
+                .map_or(false, |cl_value| cl_value.cl_type() == cl_type)
+        })
+}
@@ -1,9 +1,10 @@
 use std::collections::BTreeMap;
 
 use crate::{
-    ledger::{Element, TxnPhase},
-    parser::{runtime_args::parse_optional_arg, utils::timestamp_to_seconds_res},
-    utils::parse_public_key,
+    error::Error,
+    ledger::{Element, TxnPhase, ValueKind},
+    parser::{utils::timestamp_to_seconds_res, AmountFormat, ParserConfig},
+    utils::{cl_value_to_string, parse_public_key},
 };
 use casper_execution_engine::core::engine_state::ExecutableDeployItem;
 use casper_hashing::Digest;
@@ -17,36 +18,60 @@ use thousands::Separable;
 
 use super::{
     auction::{
-        is_delegate, is_redelegate, is_undelegate, parse_delegation, parse_redelegation,
-        parse_undelegation,
+        is_activate_bid, is_add_bid, is_delegate, is_redelegate, is_system_auction_target,
+        is_undelegate, is_withdraw_bid, parse_activate_bid, parse_add_bid, parse_delegation,
+        parse_redelegation, parse_undelegation, parse_withdraw_bid,
     },
+    cep18::{parse_token_call, token_entry_point},
+    cep78::{nft_entry_point, parse_nft_call},
     runtime_args::{parse_runtime_args, parse_transfer_args},
 };
 
-pub(crate) fn parse_deploy_header(dh: &DeployHeader) -> Vec<Element> {
+pub(crate) fn parse_deploy_header(dh: &DeployHeader) -> Result<Vec<Element>, Error> {
     let mut elements = vec![];
     elements.push(Element::regular("chain ID", dh.chain_name().to_string()));
-    elements.push(Element::regular("account", parse_public_key(dh.account())));
+    elements.push(
+        Element::regular("account", parse_public_key(dh.account())?).with_kind(ValueKind::Hex),
+    );
     elements.push(Element::expert(
         "timestamp",
         timestamp_to_seconds_res(dh.timestamp()),
     ));
     elements.push(Element::expert("ttl", format!("{}", dh.ttl())));
-    elements.push(Element::expert("gas price", format!("{}", dh.gas_price())));
-    elements.push(Element::expert(
-        "Deps #",
-        format!("{:?}", dh.dependencies().len()),
-    ));
-    elements
+    elements.push(
+        Element::expert("gas price", format!("{}", dh.gas_price())).with_kind(ValueKind::Numeric),
+    );
+    elements.push(
+        Element::expert("Deps #", format!("{:?}", dh.dependencies().len()))
+            .with_kind(ValueKind::Numeric),
+    );
+    Ok(elements)
 }
 
-pub(crate) fn parse_phase(item: &ExecutableDeployItem, phase: TxnPhase) -> Vec<Element> {
-    if is_delegate(item) {
-        parse_delegation(item)
-    } else if is_undelegate(item) {
-        parse_undelegation(item)
-    } else if is_redelegate(item) {
-        parse_redelegation(item)
+pub(crate) fn parse_phase(
+    item: &ExecutableDeployItem,
+    phase: TxnPhase,
+    chain_name: &str,
+    config: &ParserConfig,
+) -> Result<Vec<Element>, Error> {
+    // Look-alikes of the auction contract are displayed as generic contract calls.
+    let auction_call = is_system_auction_target(item, chain_name);
+    if auction_call && is_delegate(item)? {
+        parse_delegation(item, config)
+    } else if auction_call && is_undelegate(item)? {
+        parse_undelegation(item, config)
+    } else if auction_call && is_redelegate(item)? {
+        parse_redelegation(item, config)
+    } else if auction_call && is_add_bid(item)? {
+        parse_add_bid(item, config)
+    } else if auction_call && is_withdraw_bid(item)? {
+        parse_withdraw_bid(item, config)
+    } else if auction_call && is_activate_bid(item)? {
+        parse_activate_bid(item)
+    } else if let Some(entry_point) = token_entry_point(item) {
+        parse_token_call(item, phase, entry_point)
+    } else if nft_entry_point(item).is_some() {
+        parse_nft_call(item, phase)
     } else {
         let mut elements: Vec<Element> = deploy_type(phase, item);
         match item {
@@ -54,59 +79,58 @@ pub(crate) fn parse_phase(item: &ExecutableDeployItem, phase: TxnPhase) -> Vec<E
                 if is_system_payment(phase, module_bytes) =>
             {
                 // The only required argument for the system payment is `amount`.
-                elements.extend(parse_fee(args).into_iter());
+                elements.extend(parse_fee(args, config)?);
                 let args_sans_amount = remove_amount_arg(args.clone());
                 if !args_sans_amount.is_empty() {
                     // If system payment had more args than the required `amount` then they should be parsed.
-                    elements.extend(parse_runtime_args(&phase, &args));
+                    elements.extend(parse_runtime_args(&phase, args, config)?);
                 }
             }
             ExecutableDeployItem::ModuleBytes {
                 module_bytes: _,
                 args,
             } => {
-                elements.extend(parse_amount(args));
-                elements.extend(parse_runtime_args(&phase, &args));
+                elements.extend(parse_amount(args, config)?);
+                elements.extend(parse_runtime_args(&phase, args, config)?);
             }
             ExecutableDeployItem::StoredContractByHash {
                 entry_point, args, ..
             } => {
                 elements.push(entrypoint(entry_point));
-                elements.extend(parse_amount(args));
-                elements.extend(parse_runtime_args(&phase, &args));
+                elements.extend(parse_amount(args, config)?);
+                elements.extend(parse_runtime_args(&phase, args, config)?);
             }
             ExecutableDeployItem::StoredContractByName {
                 entry_point, args, ..
             } => {
                 elements.push(entrypoint(entry_point));
-                elements.extend(parse_amount(args));
-                elements.extend(parse_runtime_args(&phase, &args));
+                elements.extend(parse_amount(args, config)?);
+                elements.extend(parse_runtime_args(&phase, args, config)?);
             }
             ExecutableDeployItem::StoredVersionedContractByHash {
                 entry_point, args, ..
             } => {
                 elements.push(entrypoint(entry_point));
-                elements.extend(parse_amount(args));
-                elements.extend(parse_runtime_args(&phase, &args));
+                elements.extend(parse_amount(args, config)?);
+                elements.extend(parse_runtime_args(&phase, args, config)?);
             }
             ExecutableDeployItem::StoredVersionedContractByName {
                 entry_point, args, ..
             } => {
                 elements.push(entrypoint(entry_point));
-                elements.extend(parse_amount(args));
-                elements.extend(parse_runtime_args(&phase, &args));
+                elements.extend(parse_amount(args, config)?);
+                elements.extend(parse_runtime_args(&phase, args, config)?);
             }
             ExecutableDeployItem::Transfer { args } => {
-                elements.extend(parse_transfer_args(args));
+                elements.extend(parse_transfer_args(args, config)?);
                 let args_sans_transfer = remove_transfer_args(args.clone());
                 if !args_sans_transfer.is_empty() {
-                    println!("{:?}", args_sans_transfer);
                     // If there are more arguments left that were not used, display digest of args.
-                    elements.extend(parse_runtime_args(&phase, &args));
+                    elements.extend(parse_runtime_args(&phase, args, config)?);
                 }
             }
         }
-        elements
+        Ok(elements)
     }
 }
 
@@ -131,7 +155,7 @@ pub(crate) fn deploy_type(phase: TxnPhase, item: &ExecutableDeployItem) -> Vec<E
                     // Session|Payment: contract
                     Element::regular(&phase_label, "contract".to_string()),
                     // Cntrct hash: <hash of contract bytes>
-                    Element::regular("Cntrct hash", contract_hash),
+                    Element::regular("Cntrct hash", contract_hash).with_kind(ValueKind::Hex),
                 ]
             }
         }
@@ -140,7 +164,7 @@ pub(crate) fn deploy_type(phase: TxnPhase, item: &ExecutableDeployItem) -> Vec<E
                 // Session|Payment: by-hash
                 Element::regular(&phase_label, "by-hash".to_string()),
                 // Address: <contract address>
-                Element::regular("address", format!("{}", hash)),
+                Element::regular("address", format!("{}", hash)).with_kind(ValueKind::Hex),
             ]
         }
         ExecutableDeployItem::StoredContractByName { name, .. } => {
@@ -156,7 +180,7 @@ pub(crate) fn deploy_type(phase: TxnPhase, item: &ExecutableDeployItem) -> Vec<E
                 // Session|Payment: by-hash-versioned
                 Element::regular(&phase_label, "by-hash-versioned".to_string()),
                 // Address: <contract address>
-                Element::regular("address", hash.to_string()),
+                Element::regular("address", hash.to_string()).with_kind(ValueKind::Hex),
                 // Version: <version>
                 parse_version(version),
             ]
@@ -210,59 +234,144 @@ fn remove_transfer_args(args: RuntimeArgs) -> RuntimeArgs {
     tree.into()
 }
 
-fn format_amount(motes: U512) -> String {
+// 1 CSPR is 10^9 motes.
+const MOTES_PER_CSPR: u64 = 1_000_000_000;
+
+fn format_motes(motes: U512) -> String {
     format!("{} motes", motes.separate_with_spaces())
 }
 
-pub(crate) fn parse_fee(args: &RuntimeArgs) -> Option<Element> {
-    parse_motes(args, "fee")
+// Exact amount in CSPR: the fractional part has at most nine digits, so nothing is rounded.
+fn format_cspr(motes: U512) -> String {
+    let (cspr, fraction) = motes.div_mod(U512::from(MOTES_PER_CSPR));
+    if fraction.is_zero() {
+        return format!("{} CSPR", cspr.separate_with_spaces());
+    }
+    let fraction = format!("{:09}", fraction.as_u64());
+    format!(
+        "{}.{} CSPR",
+        cspr.separate_with_spaces(),
+        fraction.trim_end_matches('0')
+    )
 }
 
-pub(crate) fn parse_amount(args: &RuntimeArgs) -> Option<Element> {
-    parse_motes(args, "amount")
+pub(crate) fn parse_fee(args: &RuntimeArgs, config: &ParserConfig) -> Result<Vec<Element>, Error> {
+    parse_motes(args, "fee", config.amount_format())
 }
 
-fn parse_motes(args: &RuntimeArgs, ledger_label: &str) -> Option<Element> {
-    let f = |amount_str: String| {
-        let motes_amount = U512::from_dec_str(&amount_str).unwrap();
-        format_amount(motes_amount)
+pub(crate) fn parse_amount(
+    args: &RuntimeArgs,
+    config: &ParserConfig,
+) -> Result<Vec<Element>, Error> {
+    parse_motes(args, "amount", config.amount_format())
+}
+
+fn parse_motes(
+    args: &RuntimeArgs,
+    ledger_label: &str,
+    format: AmountFormat,
+) -> Result<Vec<Element>, Error> {
+    let motes = match args.get(mint::ARG_AMOUNT) {
+        Some(cl_value) => {
+            let amount_str = cl_value_to_string(cl_value)?;
+            U512::from_dec_str(&amount_str).map_err(|_| Error::InvalidAmount(amount_str))?
+        }
+        None => return Ok(vec![]),
     };
-    parse_optional_arg(args, mint::ARG_AMOUNT, ledger_label, false, f)
+    let elements = match format {
+        AmountFormat::Cspr => vec![Element::regular(ledger_label, format_cspr(motes))],
+        AmountFormat::Motes => vec![Element::regular(ledger_label, format_motes(motes))],
+        AmountFormat::Both => vec![
+            Element::regular_only(ledger_label, format_cspr(motes)),
+            Element::expert(ledger_label, format_motes(motes)),
+        ],
+    };
+    Ok(elements
+        .into_iter()
+        .map(|element| element.with_kind(ValueKind::Numeric))
+        .collect())
 }
 
 #[cfg(test)]
 mod amount {
-    use casper_types::U512;
+    use casper_types::{runtime_args, RuntimeArgs, U512};
 
-    use crate::parser::deploy::format_amount;
+    use crate::{
+        error::Error,
+        ledger::Element,
+        parser::{
+            deploy::{format_cspr, format_motes, parse_amount},
+            AmountFormat, ParserConfig,
+        },
+    };
 
     #[test]
     fn amount_space_separated() {
         let one: U512 = 1u8.into();
         let expected = "1 motes".to_string();
-        assert_eq!(expected, format_amount(one));
+        assert_eq!(expected, format_motes(one));
         let thousand: U512 = 1_000u32.into();
         let expected = "1 000 motes".to_string();
-        assert_eq!(expected, format_amount(thousand));
+        assert_eq!(expected, format_motes(thousand));
         let ten_thousand: U512 = 10_000u64.into();
         let expected = "10 000 motes".to_string();
-        assert_eq!(expected, format_amount(ten_thousand));
+        assert_eq!(expected, format_motes(ten_thousand));
         let ten_billion: U512 = U512::from(10000000000u64);
         let expected = "10 000 000 000 motes".to_string();
-        assert_eq!(expected, format_amount(ten_billion));
+        assert_eq!(expected, format_motes(ten_billion));
     }
-}
 
-pub(crate) fn identity<T>(el: T) -> T {
-    el
+    #[test]
+    fn amount_in_cspr() {
+        assert_eq!("0 CSPR", format_cspr(U512::zero()));
+        assert_eq!("0.000000001 CSPR", format_cspr(U512::one()));
+        assert_eq!("0.999999999 CSPR", format_cspr(U512::from(999_999_999u64)));
+        assert_eq!("1 CSPR", format_cspr(U512::from(1_000_000_000u64)));
+        assert_eq!(
+            "1.000000001 CSPR",
+            format_cspr(U512::from(1_000_000_001u64))
+        );
+        assert_eq!("2.5 CSPR", format_cspr(U512::from(2_500_000_000u64)));
+        assert_eq!(
+            "10 000.05 CSPR",
+            format_cspr(U512::from(10_000_050_000_000u64))
+        );
+        // Leading zeros of the fractional part are kept.
+        assert!(format_cspr(U512::MAX).ends_with(" 433 649.006084095 CSPR"));
+    }
+
+    #[test]
+    fn both_formats_split_between_modes() {
+        let args = runtime_args! { "amount" => U512::from(2_500_000_000u64) };
+        let config = ParserConfig::default().with_amount_format(AmountFormat::Both);
+        let elements = parse_amount(&args, &config).unwrap();
+        let values = |expert| -> Vec<&str> {
+            elements
+                .iter()
+                .filter(|element| element.is_visible(expert))
+                .map(Element::value)
+                .collect()
+        };
+        assert_eq!(vec!["2.5 CSPR"], values(false));
+        assert_eq!(vec!["2 500 000 000 motes"], values(true));
+    }
+
+    #[test]
+    fn invalid_amount_is_an_error() {
+        let args = runtime_args! { "amount" => "ten motes" };
+        assert_eq!(
+            Some(Error::InvalidAmount("ten motes".to_string())),
+            parse_amount(&args, &ParserConfig::default()).err()
+        );
+    }
 }
 
 pub(crate) fn parse_approvals(d: &Deploy) -> Vec<Element> {
     let approvals_count = d.approvals().len();
-    vec![Element::expert(
-        "Approvals #",
-        format!("{}", approvals_count),
-    )]
+    vec![
+        Element::expert("Approvals #", format!("{}", approvals_count))
+            .with_kind(ValueKind::Numeric),
+    ]
 }
 
 fn entrypoint(entry_point: &str) -> Element {
@@ -1,63 +1,106 @@
-use crate::ledger::{Element, TxnPhase};
+use std::collections::BTreeMap;
+
+use crate::error::Error;
+use crate::ledger::{Element, TxnPhase, ValueKind};
 use crate::utils::cl_value_to_string;
 use casper_types::bytesrepr::ToBytes;
 use casper_types::system::mint::{ARG_ID, ARG_SOURCE, ARG_TARGET, ARG_TO};
-use casper_types::RuntimeArgs;
+use casper_types::{CLType, CLValue, RuntimeArgs};
 
-use super::deploy::{identity, parse_amount};
+use super::{deploy::parse_amount, ArgsLimits, ParserConfig};
 
-/// Parses all contract arguments into a form:
+/// Displays the hash of the contract arguments and, in expert mode, when enabled and within the limits
+/// of the config, all the arguments in a form:
 /// arg-n-name: <name>
 /// arg-n-val: <val>
 /// where n is the ordinal number of the argument.
-pub(crate) fn parse_runtime_args(phase: &TxnPhase, ra: &RuntimeArgs) -> Vec<Element> {
+pub(crate) fn parse_runtime_args(
+    phase: &TxnPhase,
+    ra: &RuntimeArgs,
+    config: &ParserConfig,
+) -> Result<Vec<Element>, Error> {
     let mut elements: Vec<Element> = vec![];
     if !ra.is_empty() {
         let args_digest =
             casper_hashing::Digest::hash(ToBytes::to_bytes(ra).expect("ToBytes to work."));
         let args_hash = base16::encode_lower(&args_digest);
-        elements.push(Element::regular(
-            "args hash",
-            format!("{}-{}", phase.to_string().to_lowercase(), args_hash),
-        ));
+        elements.push(
+            Element::regular(
+                "args hash",
+                format!("{}-{}", phase.to_string().to_lowercase(), args_hash),
+            )
+            .with_kind(ValueKind::Hex),
+        );
     }
 
-    // NOTE: The code that follows would iterate over all args and parse them
-    // for Ledger presentation in a following format:
-    // Arg-n-name: <name>
-    // Arg-n-val: <value>
-    // But this could lead to very long confirmation screens in Ledger,
-    // so we opted for shorter form above: display just hash of the runtime args.
-    // If we ever decide to bring back the more elaborate version, this code would do it.
-    // let named_args: BTreeMap<String, CLValue> = ra.clone().into();
-    // for (idx, (name, value)) in named_args.iter().enumerate() {
-    //     let name_label = format!("arg-{}-name", idx);
-    //     elements.push(Element::expert(&name_label, name.to_string()));
-    //     let value_label = format!("arg-{}-val", idx);
-    //     let value_str = cl_value_to_string(value);
-    //     elements.push(Element::expert(&value_label, value_str));
-    // }
-    elements
+    // Listing all the arguments could lead to very long confirmation screens in Ledger,
+    // so by default we display just the hash of the runtime args.
+    if let Some(limits) = config.args_limits() {
+        elements.extend(list_args(ra, &limits)?);
+    }
+    Ok(elements)
+}
+
+// Lists the arguments as expert elements. Returns no elements when they exceed the limits.
+fn list_args(ra: &RuntimeArgs, limits: &ArgsLimits) -> Result<Vec<Element>, Error> {
+    let named_args: BTreeMap<String, CLValue> = ra.clone().into();
+    if named_args.len() > limits.max_count.min(ArgsLimits::MAX_COUNT) {
+        return Ok(vec![]);
+    }
+    let mut elements = vec![];
+    for (idx, (name, value)) in named_args.iter().enumerate() {
+        if cl_type_depth(value.cl_type()) > limits.max_depth {
+            return Ok(vec![]);
+        }
+        let value_str = cl_value_to_string(value)?;
+        if value_str.chars().count() > limits.max_value_chars {
+            return Ok(vec![]);
+        }
+        let name_label = format!("arg-{}-name", idx);
+        elements.push(Element::expert(&name_label, name.to_string()));
+        let value_label = format!("arg-{}-val", idx);
+        elements.push(Element::expert(&value_label, value_str));
+    }
+    Ok(elements)
 }
 
-pub(crate) fn parse_optional_arg<F: Fn(String) -> String>(
+// Nesting depth of the type: 0 for simple types, one more than the deepest inner type for the compound ones.
+fn cl_type_depth(cl_type: &CLType) -> usize {
+    let inner: Vec<&CLType> = match cl_type {
+        CLType::Option(inner) | CLType::List(inner) => vec![inner.as_ref()],
+        CLType::Result { ok, err } => vec![ok.as_ref(), err.as_ref()],
+        CLType::Map { key, value } => vec![key.as_ref(), value.as_ref()],
+        CLType::Tuple1(types) => types.iter().map(AsRef::as_ref).collect(),
+        CLType::Tuple2(types) => types.iter().map(AsRef::as_ref).collect(),
+        CLType::Tuple3(types) => types.iter().map(AsRef::as_ref).collect(),
+        _ => return 0,
+    };
+    1 + inner
+        .into_iter()
+        .map(cl_type_depth)
+        .max()
+        .unwrap_or_default()
+}
+
+pub(crate) fn parse_optional_arg<F: Fn(String) -> Result<String, Error>>(
     args: &RuntimeArgs,
     key: &str,
     label: &str,
     expert: bool,
+    kind: ValueKind,
     f: F,
-) -> Option<Element> {
+) -> Result<Option<Element>, Error> {
     match args.get(key) {
         Some(cl_value) => {
-            let value = f(cl_value_to_string(cl_value));
+            let value = f(cl_value_to_string(cl_value)?)?;
             let element = if expert {
                 Element::expert(label, value)
             } else {
                 Element::regular(label, value)
             };
-            Some(element)
+            Ok(Some(element.with_kind(kind)))
         }
-        None => None,
+        None => Ok(None),
     }
 }
 
@@ -67,15 +110,100 @@ pub(crate) fn parse_optional_arg<F: Fn(String) -> String>(
 /// * ID
 /// Optional fields:
 /// * source
-pub(crate) fn parse_transfer_args(args: &RuntimeArgs) -> Vec<Element> {
-    let mut elements: Vec<Element> = parse_optional_arg(args, ARG_TO, "recipient", false, identity)
-        .into_iter()
-        .collect();
-    elements.extend(parse_optional_arg(args, ARG_SOURCE, "from", true, identity).into_iter());
+pub(crate) fn parse_transfer_args(
+    args: &RuntimeArgs,
+    config: &ParserConfig,
+) -> Result<Vec<Element>, Error> {
+    let mut elements: Vec<Element> =
+        parse_optional_arg(args, ARG_TO, "recipient", false, ValueKind::Hex, Ok)?
+            .into_iter()
+            .collect();
+    elements.extend(
+        parse_optional_arg(args, ARG_SOURCE, "from", true, ValueKind::Hex, Ok)?.into_iter(),
+    );
     elements.extend(parse_optional_arg(
-        args, ARG_TARGET, "target", false, identity,
-    ));
-    elements.extend(parse_amount(args).into_iter());
-    elements.extend(parse_optional_arg(args, ARG_ID, "ID", true, identity).into_iter());
+        args,
+        ARG_TARGET,
+        "target",
+        false,
+        ValueKind::Hex,
+        Ok,
+    )?);
+    elements.extend(parse_amount(args, config)?);
     elements
+        .extend(parse_optional_arg(args, ARG_ID, "ID", true, ValueKind::Numeric, Ok)?.into_iter());
+    Ok(elements)
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{runtime_args, RuntimeArgs, U512};
+
+    use super::parse_runtime_args;
+    use crate::{
+        ledger::{DeviceProfile, TxnPhase},
+        parser::{ArgsLimits, ParserConfig},
+    };
+
+    fn labels(args: &RuntimeArgs, config: &ParserConfig) -> Vec<String> {
+        parse_runtime_args(&TxnPhase::Session, args, config)
+            .unwrap()
+            .iter()
+            .map(|element| element.name().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn lists_args_within_limits() {
+        let args = runtime_args! { "amount" => U512::from(10u64), "ids" => vec![Some(1u8)] };
+        let hash_only = vec!["Args hash".to_string()];
+        assert_eq!(hash_only, labels(&args, &ParserConfig::default()));
+
+        let limits = ArgsLimits::default();
+        let listed = vec![
+            "Args hash",
+            "Arg-0-name",
+            "Arg-0-val",
+            "Arg-1-name",
+            "Arg-1-val",
+        ];
+        let config = ParserConfig::default().with_args_listing(limits);
+        assert_eq!(listed, labels(&args, &config));
+
+        let too_deep = ArgsLimits {
+            max_depth: 1,
+            ..limits
+        };
+        let config = ParserConfig::default().with_args_listing(too_deep);
+        assert_eq!(hash_only, labels(&args, &config));
+
+        let too_many = ArgsLimits {
+            max_count: 1,
+            ..limits
+        };
+        let config = ParserConfig::default().with_args_listing(too_many);
+        assert_eq!(hash_only, labels(&args, &config));
+    }
+
+    #[test]
+    fn listed_labels_fit_the_smallest_display() {
+        let unlimited = ArgsLimits {
+            max_count: usize::MAX,
+            ..ArgsLimits::default()
+        };
+        let config = ParserConfig::default().with_args_listing(unlimited);
+        let mut args = RuntimeArgs::new();
+        for idx in 0..ArgsLimits::MAX_COUNT {
+            args.insert(format!("arg{:03}", idx), 1u8).unwrap();
+        }
+        let listed = labels(&args, &config);
+        assert_eq!(1 + 2 * ArgsLimits::MAX_COUNT, listed.len());
+        assert_eq!("Arg-99-name", listed[listed.len() - 2]);
+        assert!(listed
+            .iter()
+            .all(|label| label.chars().count() <= DeviceProfile::NANO_S.label_chars));
+
+        args.insert("arg100", 1u8).unwrap();
+        assert_eq!(vec!["Args hash".to_string()], labels(&args, &config));
+    }
 }
@@ -0,0 +1,82 @@
+//! Hashes of the system contracts, which differ between the chains.
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{ContractHash, ContractPackageHash};
+
+use crate::Network;
+
+/// Hashes of the system auction contract and its package, by the name of the chain.
+const AUCTION_CONTRACTS: [(&str, &str, &str); 2] = [
+    (
+        Network::MAINNET_CHAIN_NAME,
+        "ccb576d6ce6dec84a551e48f0d0b7af89ddba44c7390b690036257a04a3ae9ea",
+        "86f2d45f024d7bb7fb5266b2390d7c253b588a0a16ebd946a60cb4314600af74",
+    ),
+    (
+        Network::TESTNET_CHAIN_NAME,
+        "93d923e336b20a4c4ca14d592b60e5bd3fe330775618290104f9beb326db7ae2",
+        "e375d42c29c0e4b2baefa63cf2d70af34439eda851e08129d8515515d63bd6a9",
+    ),
+];
+
+/// System auction contract of a chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SystemAuction {
+    pub(crate) contract_hash: ContractHash,
+    pub(crate) package_hash: ContractPackageHash,
+}
+
+impl SystemAuction {
+    /// Whether the deploy item calls this auction contract.
+    ///
+    /// Contract names are resolved through the named keys of the deploy's account, which can point
+    /// at any contract, so only the calls by hash can be verified.
+    pub(crate) fn is_called_by(&self, item: &ExecutableDeployItem) -> bool {
+        match item {
+            ExecutableDeployItem::StoredContractByHash { hash, .. } => *hash == self.contract_hash,
+            ExecutableDeployItem::StoredVersionedContractByHash { hash, .. } => {
+                *hash == self.package_hash
+            }
+            ExecutableDeployItem::ModuleBytes { .. }
+            | ExecutableDeployItem::StoredContractByName { .. }
+            | ExecutableDeployItem::StoredVersionedContractByName { .. }
+            | ExecutableDeployItem::Transfer { .. } => false,
+        }
+    }
+}
+
+/// Returns the system auction contract of the chain, if it's one of the public Casper networks.
+pub(crate) fn system_auction(chain_name: &str) -> Option<SystemAuction> {
+    AUCTION_CONTRACTS
+        .iter()
+        .find(|(name, _, _)| *name == chain_name)
+        .map(|(_, contract_hash, package_hash)| SystemAuction {
+            contract_hash: ContractHash::new(decode_hash(contract_hash)),
+            package_hash: ContractPackageHash::new(decode_hash(package_hash)),
+        })
+}
+
+fn decode_hash(hash: &str) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    hex::decode_to_slice(hash, &mut bytes).expect("valid system contract hash");
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{ContractHash, RuntimeArgs};
+
+    use super::system_auction;
+    use crate::parser::test_utils::{call, call_by_hash};
+
+    #[test]
+    fn auction_differs_between_chains() {
+        let mainnet = system_auction("casper").unwrap();
+        let testnet = system_auction("casper-test").unwrap();
+        let delegate = |hash: ContractHash| call_by_hash(hash, "delegate", RuntimeArgs::new());
+        assert!(mainnet.is_called_by(&delegate(mainnet.contract_hash)));
+        assert!(!testnet.is_called_by(&delegate(mainnet.contract_hash)));
+        assert!(!mainnet.is_called_by(&call("delegate", RuntimeArgs::new())));
+        assert_eq!(None, system_auction("mainnet"));
+    }
+}
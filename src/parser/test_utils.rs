@@ -0,0 +1,22 @@
+//! Fixtures shared by the parser's tests.
+
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{ContractHash, RuntimeArgs};
+
+/// Call to the entry point of the contract with the hash.
+pub(crate) fn call_by_hash(
+    hash: ContractHash,
+    entry_point: &str,
+    args: RuntimeArgs,
+) -> ExecutableDeployItem {
+    ExecutableDeployItem::StoredContractByHash {
+        hash,
+        entry_point: entry_point.to_string(),
+        args,
+    }
+}
+
+/// Call to the entry point of an arbitrary contract.
+pub(crate) fn call(entry_point: &str, args: RuntimeArgs) -> ExecutableDeployItem {
+    call_by_hash(ContractHash::new([1; 32]), entry_point, args)
+}
@@ -1,27 +1,68 @@
+/// Whether a sample is expected to be accepted by Ledger, in each of the display modes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Validity {
+    pub regular: bool,
+    pub expert: bool,
+}
+
+impl Validity {
+    /// Valid only in the modes where both `self` and `other` are valid.
+    pub fn and(self, other: Validity) -> Validity {
+        Validity {
+            regular: self.regular && other.regular,
+            expert: self.expert && other.expert,
+        }
+    }
+}
+
+/// The same validity in both modes.
+impl From<bool> for Validity {
+    fn from(valid: bool) -> Self {
+        Validity {
+            regular: valid,
+            expert: valid,
+        }
+    }
+}
+
 /// A generic wrapper around any type `T` that can be considered as being a sample test vector.
-/// It has associated `label` that described the sample and validity flag (`valid`)
-/// indicating whether the sample is correct - i.e. whether it is a valid CasperNetwork transaction.
+/// It has associated `label` that described the sample and validity flags (`valid`)
+/// indicating whether the sample is correct - i.e. whether it is a valid CasperNetwork transaction
+/// that Ledger accepts in regular and expert mode.
 #[derive(Debug, Clone)]
-pub(crate) struct Sample<V> {
+pub struct Sample<V> {
     label: String,
     sample: V,
-    valid: bool,
+    valid: Validity,
 }
 
 impl<V> Sample<V> {
-    pub(crate) fn new<S: Into<String>>(label: S, sample: V, valid: bool) -> Sample<V> {
+    pub fn new<S: Into<String>, T: Into<Validity>>(label: S, sample: V, valid: T) -> Sample<V> {
         Sample {
             label: label.into(),
             sample,
-            valid,
+            valid: valid.into(),
         }
     }
 
-    pub(crate) fn destructure(self) -> (String, V, bool) {
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Whether the sample is valid in both modes.
+    pub fn is_valid(&self) -> bool {
+        self.valid.regular && self.valid.expert
+    }
+
+    pub fn validity(&self) -> Validity {
+        self.valid
+    }
+
+    pub fn destructure(self) -> (String, V, Validity) {
         (self.label, self.sample, self.valid)
     }
 
-    pub(crate) fn add_label(&mut self, label: String) {
+    pub fn add_label(&mut self, label: String) {
         self.label = format!("{}__{}", self.label, label);
     }
 }
@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{collections::HashMap, fmt::Display, str::FromStr};
 
 use casper_execution_engine::core::engine_state::ExecutableDeployItem;
 use casper_node::types::{Deploy, DeployHash, TimeDiff, Timestamp};
@@ -6,18 +6,27 @@ use casper_types::{
     account::AccountHash, AccessRights, AsymmetricType, CLValue, Key, PublicKey, RuntimeArgs,
     SecretKey, URef, U512,
 };
+use glob::Pattern;
 use rand::{prelude::*, Rng};
 
-use auction::{delegate, undelegate};
+use auction::{activate_bid, add_bid, delegate, spoofing, undelegate, withdraw_bid};
 
-use crate::sample::Sample;
+use crate::{
+    ledger::ZondaxRepr,
+    parser::system_auction,
+    sample::{Sample, Validity},
+    Network,
+};
 
 use self::{auction::redelegate, commons::UREF_ADDR};
 
 mod auction;
+pub(crate) mod cep18;
+pub(crate) mod cep78;
 mod commons;
 mod generic;
 mod native_transfer;
+mod non_ascii;
 mod system_payment;
 
 // From the chainspec.
@@ -178,24 +187,34 @@ fn make_deploy_sample(
     ttl: TimeDiff,
     dependencies: Vec<DeployHash>,
     signing_keys: &[SecretKey],
+    network: &Network,
 ) -> Sample<Deploy> {
     let (main_key, secondary_keys) = signing_keys.split_at(1);
     let (payment_label, payment, payment_validity) = payment.destructure();
     let (session_label, session, session_validity) = session.destructure();
+    // Header fields are displayed only in expert mode, so only expert mode can reject a deploy
+    // that exceeds their chainspec limits.
+    let header_validity = Validity {
+        regular: true,
+        expert: ttl <= MAX_TTL
+            && dependencies.len() <= MAX_DEPS_COUNT as usize
+            && signing_keys.len() <= MAX_APPROVALS_COUNT as usize,
+    };
 
     let deploy = Deploy::new(
         Timestamp::from_str("2021-05-04T14:20:35.104Z").unwrap(),
         ttl,
         2,
         dependencies,
-        String::from("mainnet"),
+        network.chain_name().to_string(),
         payment,
         session,
         &main_key[0],
         None,
     );
 
-    let mut sample = Sample::new(session_label, deploy, session_validity && payment_validity);
+    let validity = session_validity.and(payment_validity).and(header_validity);
+    let mut sample = Sample::new(session_label, deploy, validity);
     sample.add_label(payment_label);
 
     // Sign deploy with possibly multiple keys.
@@ -237,6 +256,7 @@ fn random_keys(key_count: u8) -> Vec<SecretKey> {
 // creating n^2 deploy samples.
 fn construct_samples<R: Rng>(
     rng: &mut R,
+    network: &Network,
     session_samples: Vec<Sample<ExecutableDeployItem>>,
     payment_samples: Vec<Sample<ExecutableDeployItem>>,
 ) -> Vec<Sample<Deploy>> {
@@ -264,64 +284,82 @@ fn construct_samples<R: Rng>(
             ttls.shuffle(rng);
             let ttl = ttls.first().cloned().unwrap();
 
-            let sample_deploy =
-                make_deploy_sample(session.clone(), payment.clone(), ttl, dependencies, &keys);
+            let sample_deploy = make_deploy_sample(
+                session.clone(),
+                payment.clone(),
+                ttl,
+                dependencies,
+                &keys,
+                network,
+            );
             samples.push(sample_deploy);
         }
     }
     samples
 }
 
-pub(crate) fn redelegate_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
-    let valid_samples = redelegate::valid();
+pub fn redelegate_samples<R: Rng>(rng: &mut R, network: &Network) -> Vec<Sample<Deploy>> {
+    let valid_samples = redelegate::valid(network);
     let valid_payment_samples = vec![system_payment::valid()];
 
-    let mut samples = construct_samples(rng, valid_samples, valid_payment_samples);
+    let mut samples = construct_samples(rng, network, valid_samples, valid_payment_samples);
     let invalid_samples = redelegate::invalid();
     let invalid_payment_samples = vec![system_payment::invalid(), system_payment::valid()];
     samples.extend(construct_samples(
         rng,
+        network,
         invalid_samples,
         invalid_payment_samples,
     ));
     samples
 }
 
-pub(crate) fn generic_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
+pub fn generic_samples<R: Rng>(rng: &mut R, network: &Network) -> Vec<Sample<Deploy>> {
     let valid_samples = generic::valid(rng);
     let valid_payment_samples = vec![system_payment::valid()];
 
-    let mut samples = construct_samples(rng, valid_samples.clone(), valid_payment_samples);
+    let mut samples = construct_samples(rng, network, valid_samples.clone(), valid_payment_samples);
 
     // Generic transactions are invalid only if their payment contract is invalid.
     // Otherwise there are no rules that could be violated and make txn invalid -
     // if it has correct structure it's valid b/c we don't know what the contracts expect.
     samples.extend(construct_samples(
         rng,
+        network,
         valid_samples,
         vec![system_payment::invalid()],
     ));
     samples
 }
 
-pub(crate) fn native_transfer_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
-    let mut native_transfer_samples =
-        construct_samples(rng, native_transfer::valid(), vec![system_payment::valid()]);
+pub fn native_transfer_samples<R: Rng>(rng: &mut R, network: &Network) -> Vec<Sample<Deploy>> {
+    let mut native_transfer_samples = construct_samples(
+        rng,
+        network,
+        native_transfer::valid(),
+        vec![system_payment::valid()],
+    );
 
     native_transfer_samples.extend(construct_samples(
         rng,
+        network,
         native_transfer::invalid(),
         vec![system_payment::invalid(), system_payment::valid()],
     ));
     native_transfer_samples
 }
 
-pub(crate) fn delegate_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
-    let mut delegate_samples =
-        construct_samples(rng, delegate::valid(), vec![system_payment::valid()]);
+pub fn delegate_samples<R: Rng>(rng: &mut R, network: &Network) -> Vec<Sample<Deploy>> {
+    let mut delegate_samples = construct_samples(
+        rng,
+        network,
+        delegate::valid(network),
+        vec![system_payment::valid()],
+    );
 
     delegate_samples.extend(construct_samples(
         rng,
+        network,
         delegate::invalid(),
         vec![system_payment::invalid(), system_payment::valid()],
     ));
@@ -329,15 +367,355 @@ pub(crate) fn delegate_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
     delegate_samples
 }
 
-pub(crate) fn undelegate_samples<R: Rng>(rng: &mut R) -> Vec<Sample<Deploy>> {
-    let mut undelegate_samples =
-        construct_samples(rng, undelegate::valid(), vec![system_payment::valid()]);
+pub fn undelegate_samples<R: Rng>(rng: &mut R, network: &Network) -> Vec<Sample<Deploy>> {
+    let mut undelegate_samples = construct_samples(
+        rng,
+        network,
+        undelegate::valid(network),
+        vec![system_payment::valid()],
+    );
 
     undelegate_samples.extend(construct_samples(
         rng,
+        network,
         undelegate::invalid(),
         vec![system_payment::invalid(), system_payment::valid()],
     ));
 
     undelegate_samples
 }
+
+pub fn non_ascii_samples<R: Rng>(rng: &mut R, network: &Network) -> Vec<Sample<Deploy>> {
+    construct_samples(
+        rng,
+        network,
+        non_ascii::valid(),
+        vec![system_payment::valid()],
+    )
+}
+
+// Deploys exceeding the chainspec limits of the header fields, which only expert mode rejects.
+pub fn header_limits_samples<R: Rng>(_rng: &mut R, network: &Network) -> Vec<Sample<Deploy>> {
+    let session = native_transfer::valid()
+        .into_iter()
+        .next()
+        .expect("at least one valid native transfer sample");
+    let payment = system_payment::valid();
+
+    let cases = vec![
+        // 1 day and 1 hour.
+        ("ttl_above_max", TimeDiff::from_seconds(60 * 60 * 25), 0, 1),
+        ("deps_above_max", MAX_TTL, MAX_DEPS_COUNT + 1, 1),
+        ("approvals_above_max", MAX_TTL, 0, MAX_APPROVALS_COUNT + 1),
+    ];
+    cases
+        .into_iter()
+        .map(|(label, ttl, deps_count, key_count)| {
+            let mut sample = make_deploy_sample(
+                session.clone(),
+                payment.clone(),
+                ttl,
+                make_dependencies(deps_count),
+                &random_keys(key_count),
+                network,
+            );
+            sample.add_label(label.to_string());
+            sample
+        })
+        .collect()
+}
+
+pub fn cep18_samples<R: Rng>(rng: &mut R, network: &Network) -> Vec<Sample<Deploy>> {
+    let mut cep18_samples =
+        construct_samples(rng, network, cep18::valid(), vec![system_payment::valid()]);
+
+    cep18_samples.extend(construct_samples(
+        rng,
+        network,
+        cep18::invalid(),
+        vec![system_payment::invalid(), system_payment::valid()],
+    ));
+
+    cep18_samples
+}
+
+pub fn cep78_samples<R: Rng>(rng: &mut R, network: &Network) -> Vec<Sample<Deploy>> {
+    let mut cep78_samples =
+        construct_samples(rng, network, cep78::valid(), vec![system_payment::valid()]);
+
+    cep78_samples.extend(construct_samples(
+        rng,
+        network,
+        cep78::invalid(),
+        vec![system_payment::invalid(), system_payment::valid()],
+    ));
+
+    cep78_samples
+}
+
+pub fn add_bid_samples<R: Rng>(rng: &mut R, network: &Network) -> Vec<Sample<Deploy>> {
+    let mut add_bid_samples = construct_samples(
+        rng,
+        network,
+        add_bid::valid(network),
+        vec![system_payment::valid()],
+    );
+
+    add_bid_samples.extend(construct_samples(
+        rng,
+        network,
+        add_bid::invalid(),
+        vec![system_payment::invalid(), system_payment::valid()],
+    ));
+
+    add_bid_samples
+}
+
+pub fn withdraw_bid_samples<R: Rng>(rng: &mut R, network: &Network) -> Vec<Sample<Deploy>> {
+    let mut withdraw_bid_samples = construct_samples(
+        rng,
+        network,
+        withdraw_bid::valid(network),
+        vec![system_payment::valid()],
+    );
+
+    withdraw_bid_samples.extend(construct_samples(
+        rng,
+        network,
+        withdraw_bid::invalid(),
+        vec![system_payment::invalid(), system_payment::valid()],
+    ));
+
+    withdraw_bid_samples
+}
+
+pub fn activate_bid_samples<R: Rng>(rng: &mut R, network: &Network) -> Vec<Sample<Deploy>> {
+    let mut activate_bid_samples = construct_samples(
+        rng,
+        network,
+        activate_bid::valid(network),
+        vec![system_payment::valid()],
+    );
+
+    activate_bid_samples.extend(construct_samples(
+        rng,
+        network,
+        activate_bid::invalid(),
+        vec![system_payment::invalid(), system_payment::valid()],
+    ));
+
+    activate_bid_samples
+}
+
+// Calls to the auction look-alikes. The system auction contract is known only on the public networks,
+// so the custom networks' samples call the mainnet auction - a look-alike there as well.
+pub fn auction_spoofing_samples<R: Rng>(_rng: &mut R, network: &Network) -> Vec<Sample<Deploy>> {
+    let (auction_network, other_network) = match network {
+        Network::Testnet => (Network::Testnet, Network::Mainnet),
+        Network::Mainnet | Network::Custom(_) => (Network::Mainnet, Network::Testnet),
+    };
+    let auction =
+        system_auction(auction_network.chain_name()).expect("known auction of a public network");
+    let other_auction =
+        system_auction(other_network.chain_name()).expect("known auction of a public network");
+    let payment = system_payment::valid();
+    spoofing::samples(auction, other_auction)
+        .into_iter()
+        .map(|session| {
+            make_deploy_sample(
+                session,
+                payment.clone(),
+                MAX_TTL,
+                vec![],
+                &random_keys(1),
+                network,
+            )
+        })
+        .collect()
+}
+
+/// Families of the samples, in the order they are generated in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleFamily {
+    Undelegate,
+    Delegate,
+    NativeTransfer,
+    Redelegate,
+    Generic,
+    NonAscii,
+    HeaderLimits,
+    Cep18,
+    Cep78,
+    AddBid,
+    WithdrawBid,
+    ActivateBid,
+    AuctionSpoofing,
+}
+
+impl SampleFamily {
+    /// All the families, in the order they are generated in.
+    /// Changing the order changes the generated samples, as they share the PRNG.
+    pub const ALL: [SampleFamily; 13] = [
+        SampleFamily::Undelegate,
+        SampleFamily::Delegate,
+        SampleFamily::NativeTransfer,
+        SampleFamily::Redelegate,
+        SampleFamily::Generic,
+        SampleFamily::NonAscii,
+        SampleFamily::HeaderLimits,
+        SampleFamily::Cep18,
+        SampleFamily::Cep78,
+        SampleFamily::AddBid,
+        SampleFamily::WithdrawBid,
+        SampleFamily::ActivateBid,
+        SampleFamily::AuctionSpoofing,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SampleFamily::Undelegate => "undelegate",
+            SampleFamily::Delegate => "delegate",
+            SampleFamily::NativeTransfer => "native_transfer",
+            SampleFamily::Redelegate => "redelegate",
+            SampleFamily::Generic => "generic",
+            SampleFamily::NonAscii => "non_ascii",
+            SampleFamily::HeaderLimits => "header_limits",
+            SampleFamily::Cep18 => "cep18",
+            SampleFamily::Cep78 => "cep78",
+            SampleFamily::AddBid => "add_bid",
+            SampleFamily::WithdrawBid => "withdraw_bid",
+            SampleFamily::ActivateBid => "activate_bid",
+            SampleFamily::AuctionSpoofing => "auction_spoofing",
+        }
+    }
+
+    /// Generates samples of the family, made for the network.
+    pub fn samples<R: Rng>(&self, rng: &mut R, network: &Network) -> Vec<Sample<Deploy>> {
+        match self {
+            SampleFamily::Undelegate => undelegate_samples(rng, network),
+            SampleFamily::Delegate => delegate_samples(rng, network),
+            SampleFamily::NativeTransfer => native_transfer_samples(rng, network),
+            SampleFamily::Redelegate => redelegate_samples(rng, network),
+            SampleFamily::Generic => generic_samples(rng, network),
+            SampleFamily::NonAscii => non_ascii_samples(rng, network),
+            SampleFamily::HeaderLimits => header_limits_samples(rng, network),
+            SampleFamily::Cep18 => cep18_samples(rng, network),
+            SampleFamily::Cep78 => cep78_samples(rng, network),
+            SampleFamily::AddBid => add_bid_samples(rng, network),
+            SampleFamily::WithdrawBid => withdraw_bid_samples(rng, network),
+            SampleFamily::ActivateBid => activate_bid_samples(rng, network),
+            SampleFamily::AuctionSpoofing => auction_spoofing_samples(rng, network),
+        }
+    }
+}
+
+impl Display for SampleFamily {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for SampleFamily {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|family| family.name() == name)
+            .cloned()
+            .ok_or_else(|| {
+                let names: Vec<&str> = Self::ALL.iter().map(SampleFamily::name).collect();
+                format!(
+                    "unknown sample family '{}', expected one of: {}",
+                    name,
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+/// Generates samples of all the families for each of the networks in turn (only for the default
+/// network when none are given), each paired with its family.
+///
+/// The order of the families is fixed, so that the same seed always results in the same samples.
+/// Labels of the samples made for networks other than the default one end with the chain name.
+/// Labels are unique: samples differing only in values that aren't part of the label (e.g. amounts)
+/// get a counter appended, starting with `__2` for the second one.
+pub fn all_samples<R: Rng>(
+    rng: &mut R,
+    networks: &[Network],
+) -> Vec<(SampleFamily, Sample<Deploy>)> {
+    let default_network = Network::default();
+    let networks = if networks.is_empty() {
+        std::slice::from_ref(&default_network)
+    } else {
+        networks
+    };
+    let mut samples = vec![];
+    let mut label_counts: HashMap<String, usize> = HashMap::new();
+    for network in networks {
+        for family in SampleFamily::ALL {
+            for mut sample in family.samples(rng, network) {
+                if *network != default_network {
+                    sample.add_label(network.chain_name().to_string());
+                }
+                let count = label_counts.entry(sample.label().to_string()).or_default();
+                *count += 1;
+                if *count > 1 {
+                    sample.add_label(count.to_string());
+                }
+                samples.push((family, sample));
+            }
+        }
+    }
+    samples
+}
+
+/// Criteria for selecting a subset of the samples.
+/// Empty criteria match all the samples.
+#[derive(Debug, Clone, Default)]
+pub struct SampleFilter {
+    /// Families to select. All when empty.
+    pub families: Vec<SampleFamily>,
+    /// Pattern the sample's label has to match.
+    pub label: Option<Pattern>,
+    /// Required validity of the test vector, valid meaning valid in both modes.
+    ///
+    /// It's the validity of the test vector rather than of the sample: a sample that exceeds the page
+    /// limit or that Ledger can't display makes an invalid test vector (see [`ZondaxRepr`]), so the
+    /// criterion can be checked only once the test vector is made.
+    pub valid: Option<bool>,
+}
+
+impl SampleFilter {
+    /// Whether the sample matches the family and label criteria.
+    pub fn matches(&self, family: SampleFamily, sample: &Sample<Deploy>) -> bool {
+        (self.families.is_empty() || self.families.contains(&family))
+            && self
+                .label
+                .as_ref()
+                .map_or(true, |pattern| pattern.matches(sample.label()))
+    }
+
+    /// Whether the test vector matches the validity criterion.
+    pub fn matches_vector(&self, vector: &ZondaxRepr) -> bool {
+        self.valid.map_or(true, |valid| valid == vector.is_valid())
+    }
+
+    /// Generates samples of all the families for the networks (see [`all_samples`]) and selects the ones
+    /// matching the family and label criteria (see [`SampleFilter::matches_vector`] for the validity one).
+    /// Every sample is paired with its index in the full set of samples, so that the indices do not depend
+    /// on the criteria.
+    pub fn samples<R: Rng>(
+        &self,
+        rng: &mut R,
+        networks: &[Network],
+    ) -> Vec<(usize, SampleFamily, Sample<Deploy>)> {
+        all_samples(rng, networks)
+            .into_iter()
+            .enumerate()
+            .filter(|(_, (family, sample))| self.matches(*family, sample))
+            .map(|(index, (family, sample))| (index, family, sample))
+            .collect()
+    }
+}
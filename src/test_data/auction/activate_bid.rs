@@ -0,0 +1,80 @@
+//! Sample test vectors for the deploys of evicted validators reactivating their bids.
+//!
+//! Method name (entrypoint):
+//! `activate_bid`
+//!
+//! Arguments:
+//! | name | type |
+//! |---------|---------|
+//! | `validator_public_key` | `PublicKey` |
+
+use crate::sample::Sample;
+use crate::test_data::auction::commons::{self};
+use crate::test_data::commons::{prepend_label, sample_executables};
+use crate::Network;
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{runtime_args, AsymmetricType, PublicKey, RuntimeArgs};
+
+const ENTRY_POINT_NAME: &str = "activate_bid";
+
+#[derive(Clone, Debug)]
+struct ActivateBid {
+    validator_public_key: PublicKey,
+}
+
+impl ActivateBid {
+    fn new(validator_public_key: PublicKey) -> Self {
+        ActivateBid {
+            validator_public_key,
+        }
+    }
+}
+
+impl From<ActivateBid> for RuntimeArgs {
+    fn from(a: ActivateBid) -> Self {
+        let mut ra = RuntimeArgs::new();
+        ra.insert("validator_public_key", a.validator_public_key)
+            .unwrap();
+        ra
+    }
+}
+
+// Creates vector of sample `ActivateBid` objects, one for each of the public key algorithms.
+fn sample_activations() -> Vec<ActivateBid> {
+    let ed25519: PublicKey = PublicKey::ed25519_from_bytes([3u8; 32]).unwrap();
+    let secp256k1: PublicKey = PublicKey::secp256k1_from_bytes(
+        hex::decode(b"026e1b7a8e3243f5ff14e825b0fde15103588bb61e6ae99084968b017118e0504f").unwrap(),
+    )
+    .unwrap();
+
+    vec![ActivateBid::new(ed25519), ActivateBid::new(secp256k1)]
+}
+
+pub(crate) fn valid(network: &Network) -> Vec<Sample<ExecutableDeployItem>> {
+    let activate_bid_rargs = sample_activations().into_iter().map(Into::into).collect();
+
+    commons::valid(ENTRY_POINT_NAME, activate_bid_rargs, network)
+}
+
+pub(crate) fn invalid() -> Vec<Sample<ExecutableDeployItem>> {
+    let validator_public_key: PublicKey = PublicKey::ed25519_from_bytes([3u8; 32]).unwrap();
+    let activation = ActivateBid::new(validator_public_key.clone());
+
+    // Validator's key passed under the name of the other auction entrypoints.
+    let invalid_validator_public_key_name = runtime_args! {
+        "public_key" => validator_public_key,
+    };
+    let invalid_name = sample_executables(
+        ENTRY_POINT_NAME,
+        invalid_validator_public_key_name,
+        Some("invalid_name_validator_public_key".to_string()),
+        true,
+    )
+    .into_iter()
+    .map(|sample| prepend_label(sample, ENTRY_POINT_NAME));
+
+    commons::invalid_call(ENTRY_POINT_NAME, activation.into())
+        .into_iter()
+        .chain(invalid_name)
+        .collect()
+}
@@ -0,0 +1,79 @@
+//! Sample test vectors for the deploys of validators bidding for a slot.
+//!
+//! Method name (entrypoint):
+//! `add_bid`
+//!
+//! Arguments:
+//! | name | type |
+//! |---------|---------|
+//! | `public_key` | `PublicKey` |
+//! | `delegation_rate` | `u8` |
+//! | `amount` | `U512` |
+
+use crate::sample::Sample;
+use crate::test_data::auction::commons::{self};
+use crate::Network;
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{AsymmetricType, PublicKey, RuntimeArgs, U512};
+
+const ENTRY_POINT_NAME: &str = "add_bid";
+
+#[derive(Clone, Debug)]
+struct AddBid {
+    public_key: PublicKey,
+    delegation_rate: u8,
+    amount: U512,
+}
+
+impl AddBid {
+    fn new(public_key: PublicKey, delegation_rate: u8, amount: U512) -> Self {
+        AddBid {
+            public_key,
+            delegation_rate,
+            amount,
+        }
+    }
+}
+
+impl From<AddBid> for RuntimeArgs {
+    fn from(b: AddBid) -> Self {
+        let mut ra = RuntimeArgs::new();
+        ra.insert("public_key", b.public_key).unwrap();
+        ra.insert("delegation_rate", b.delegation_rate).unwrap();
+        ra.insert("amount", b.amount).unwrap();
+        ra
+    }
+}
+
+// Creates vector of sample `AddBid` objects, covering the edge cases
+// of the `U512` amount and of the delegation rate (a percentage).
+fn sample_bids() -> Vec<AddBid> {
+    let amount_min = U512::from(0u8);
+    let amount_mid = U512::from(100000000);
+    let amount_max = U512::MAX;
+    let amounts = vec![amount_min, amount_mid, amount_max];
+    let delegation_rates = vec![0u8, 100u8];
+
+    let public_key: PublicKey = PublicKey::ed25519_from_bytes([3u8; 32]).unwrap();
+
+    let mut bids = vec![];
+    for delegation_rate in delegation_rates {
+        for amount in &amounts {
+            bids.push(AddBid::new(public_key.clone(), delegation_rate, *amount));
+        }
+    }
+    bids
+}
+
+pub(crate) fn valid(network: &Network) -> Vec<Sample<ExecutableDeployItem>> {
+    let add_bid_rargs = sample_bids().into_iter().map(Into::into).collect();
+
+    commons::valid(ENTRY_POINT_NAME, add_bid_rargs, network)
+}
+
+pub(crate) fn invalid() -> Vec<Sample<ExecutableDeployItem>> {
+    let public_key: PublicKey = PublicKey::ed25519_from_bytes([3u8; 32]).unwrap();
+    let bid = AddBid::new(public_key, 10u8, U512::from(100000000u64));
+
+    commons::invalid_call(ENTRY_POINT_NAME, bid.into())
+}
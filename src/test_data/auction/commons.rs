@@ -1,14 +1,47 @@
+use crate::parser::system_auction;
 use crate::sample::Sample;
-use crate::test_data::commons::{prepend_label, sample_executables, sample_module_bytes};
+use crate::test_data::commons::{self, prepend_label, sample_module_bytes};
+use crate::Network;
+use casper_execution_engine::core::engine_state::executable_deploy_item::ExecutableDeployItem::{
+    StoredContractByHash, StoredVersionedContractByHash,
+};
 use casper_execution_engine::core::engine_state::ExecutableDeployItem;
-use casper_types::{runtime_args, AsymmetricType, PublicKey, RuntimeArgs, U512};
+use casper_types::{runtime_args, RuntimeArgs};
 
-pub(crate) fn valid(entrypoint: &str, ra: Vec<RuntimeArgs>) -> Vec<Sample<ExecutableDeployItem>> {
+/// Constructs valid `entrypoint` deploys made for the `network`.
+///
+/// Stored contract calls are displayed as auction deploys only when they call the system auction
+/// of the chain by its hash, so they are made only for the networks whose auction is known.
+/// Calls by name and calls to other contracts are the look-alikes of the `auction_spoofing` samples.
+pub(crate) fn valid(
+    entrypoint: &str,
+    ra: Vec<RuntimeArgs>,
+    network: &Network,
+) -> Vec<Sample<ExecutableDeployItem>> {
+    let auction = system_auction(network.chain_name());
     let mut output = vec![];
 
     for args in ra {
-        for sample in sample_executables(entrypoint, args.clone(), None, true) {
-            output.push(prepend_label(sample, entrypoint));
+        if let Some(auction) = auction {
+            let by_hash = StoredContractByHash {
+                hash: auction.contract_hash,
+                entry_point: entrypoint.to_string(),
+                args: args.clone(),
+            };
+            let by_package_hash = StoredVersionedContractByHash {
+                hash: auction.package_hash,
+                version: None,
+                entry_point: entrypoint.to_string(),
+                args: args.clone(),
+            };
+            output.push(prepend_label(
+                Sample::new("type_by_hash", by_hash, true),
+                entrypoint,
+            ));
+            output.push(prepend_label(
+                Sample::new("type_versioned_by_hash", by_package_hash, true),
+                entrypoint,
+            ));
         }
 
         let mut ra: RuntimeArgs = args;
@@ -19,65 +52,50 @@ pub(crate) fn valid(entrypoint: &str, ra: Vec<RuntimeArgs>) -> Vec<Sample<Execut
     output
 }
 
-/// Constructs transactions that are invalid (un)delegate deploys
+/// Constructs transactions that are invalid `entry_point` deploys
 /// but are valid "generic" deploys - i.e. they will still be processed by a node
 /// but will not be recognized as auction commands.
-pub(crate) fn invalid_delegation(entry_point: &str) -> Vec<Sample<ExecutableDeployItem>> {
-    let delegator: PublicKey = PublicKey::ed25519_from_bytes([1u8; 32]).unwrap();
-    let validator: PublicKey = PublicKey::ed25519_from_bytes([3u8; 32]).unwrap();
-    let amount = U512::from(100000000u32);
-
-    let valid_args = runtime_args! {
-        "delegator" => delegator.clone(),
-        "validator" => validator.clone(),
-        "amount" => amount,
-    };
-
-    let invalid_args = {
-        let missing_required_amount = runtime_args! {
-            "delegator" => delegator.clone(),
-            "validator" => validator.clone(),
-        };
-
-        let missing_required_delegator = runtime_args! {
-            "validator" => validator.clone(),
-            "amount" => amount,
-        };
+///
+/// See [`commons::invalid_call`], the `amount` is passed with an invalid type too.
+pub(crate) fn invalid_call(
+    entry_point: &str,
+    required_args: RuntimeArgs,
+) -> Vec<Sample<ExecutableDeployItem>> {
+    commons::invalid_call(
+        entry_point,
+        required_args,
+        runtime_args! { "amount" => 100000u32 },
+    )
+}
 
-        let missing_required_validator = runtime_args! {
-            "delegator" => delegator.clone(),
-            "amount" => amount
-        };
+#[cfg(test)]
+mod tests {
+    use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+    use casper_types::RuntimeArgs;
 
-        let invalid_amount_type = runtime_args! {
-            "validator" => validator,
-            "delegator" => delegator,
-            "amount" => 100000u32
-        };
+    use super::valid;
+    use crate::{parser::system_auction, Network};
 
-        // We're setting the "validity bit" to `true`, otherwise such transaction would
-        // be rejected by the Ledger Hardware and we don't want that. dApps could be written
-        // in such a way that they use similar arguments.
-        vec![
-            Sample::new("missing_amount", missing_required_amount, true),
-            Sample::new("missing_delegator", missing_required_delegator, true),
-            Sample::new("missing_validator", missing_required_validator, true),
-            Sample::new("invalid_type_amount", invalid_amount_type, true),
-        ]
-    };
+    #[test]
+    fn valid_calls_target_the_system_auction() {
+        let mainnet_auction = system_auction(Network::Mainnet.chain_name()).unwrap();
+        let mainnet = valid("delegate", vec![RuntimeArgs::new()], &Network::Mainnet);
+        assert_eq!(3, mainnet.len());
+        for sample in mainnet {
+            let (label, item, _valid) = sample.destructure();
+            assert!(
+                matches!(item, ExecutableDeployItem::ModuleBytes { .. })
+                    || mainnet_auction.is_called_by(&item),
+                "{} doesn't call the mainnet auction",
+                label
+            );
+        }
 
-    invalid_args
-        .into_iter()
-        .flat_map(|sample_ra| {
-            let (label, ra, valid) = sample_ra.destructure();
-            sample_executables(entry_point, ra, Some(label), valid)
-        })
-        .chain(sample_executables(
-            "invalid",
-            valid_args.clone(),
-            Some("invalid_entrypoint".to_string()),
-            true, // Even though entrypoint is invalid, it's possible that generic transaction (non-native auction) uses similar set of arguments but changes the entrypoint. In that case, transaction MUSTN'T be invalid b/c it will get rejected by the Ledger.
-        ))
-        .map(|sample| prepend_label(sample, entry_point))
-        .collect()
+        // The auction of the custom chains isn't known, only `ModuleBytes` sessions are displayed
+        // as auction deploys there.
+        let custom = valid("delegate", vec![RuntimeArgs::new()], &Network::default());
+        assert_eq!(1, custom.len());
+        let (_label, item, _valid) = custom[0].clone().destructure();
+        assert!(matches!(item, ExecutableDeployItem::ModuleBytes { .. }));
+    }
 }
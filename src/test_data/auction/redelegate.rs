@@ -14,6 +14,7 @@
 use crate::sample::Sample;
 use crate::test_data::auction::commons::{self};
 use crate::test_data::commons::{prepend_label, sample_executables};
+use crate::Network;
 use casper_execution_engine::core::engine_state::ExecutableDeployItem;
 use casper_types::{runtime_args, AsymmetricType, PublicKey, RuntimeArgs, U512};
 
@@ -161,10 +162,10 @@ fn sample_redelegations() -> Vec<Redelegate> {
         .collect()
 }
 
-pub(crate) fn valid() -> Vec<Sample<ExecutableDeployItem>> {
+pub(crate) fn valid(network: &Network) -> Vec<Sample<ExecutableDeployItem>> {
     let delegate_rargs = sample_redelegations().into_iter().map(Into::into).collect();
 
-    commons::valid(ENTRY_POINT_NAME, delegate_rargs)
+    commons::valid(ENTRY_POINT_NAME, delegate_rargs, network)
 }
 
 pub(crate) fn invalid() -> Vec<Sample<ExecutableDeployItem>> {
@@ -176,7 +177,7 @@ mod tests {
     fn redelegate_expected_args() {
         let mut rng = crate::TestRng::new();
 
-        let valid_sample = super::valid();
+        let valid_sample = super::valid(&crate::Network::Mainnet);
 
         fn assertion(args: &casper_types::RuntimeArgs) -> bool {
             args.get("amount").is_some()
@@ -0,0 +1,102 @@
+//! Sample test vectors for calls to contracts exposing the same entrypoints as the system auction.
+//!
+//! Only the calls to the auction contract of the chain (by its hash or its package hash) are
+//! displayed as auction deploys, the look-alikes are displayed as generic contract calls.
+
+use crate::parser::SystemAuction;
+use crate::sample::Sample;
+use crate::test_data::commons::prepend_label;
+use casper_execution_engine::core::engine_state::executable_deploy_item::ExecutableDeployItem::{
+    StoredContractByHash, StoredContractByName, StoredVersionedContractByHash,
+};
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{
+    runtime_args, AsymmetricType, ContractHash, ContractPackageHash, PublicKey, RuntimeArgs, U512,
+};
+
+fn sample_calls() -> Vec<(&'static str, RuntimeArgs)> {
+    let delegator: PublicKey = PublicKey::ed25519_from_bytes([1u8; 32]).unwrap();
+    let validator: PublicKey = PublicKey::ed25519_from_bytes([3u8; 32]).unwrap();
+    let new_validator: PublicKey = PublicKey::ed25519_from_bytes([6u8; 32]).unwrap();
+    let amount = U512::from(100000000u64);
+
+    vec![
+        (
+            "delegate",
+            runtime_args! {
+                "delegator" => delegator.clone(),
+                "validator" => validator.clone(),
+                "amount" => amount,
+            },
+        ),
+        (
+            "undelegate",
+            runtime_args! {
+                "delegator" => delegator.clone(),
+                "validator" => validator.clone(),
+                "amount" => amount,
+            },
+        ),
+        (
+            "redelegate",
+            runtime_args! {
+                "delegator" => delegator,
+                "validator" => validator,
+                "new_validator" => new_validator,
+                "amount" => amount,
+            },
+        ),
+    ]
+}
+
+/// Calls to the `auction` of the chain and to the look-alikes: contracts with other hashes,
+/// e.g. the auction of another chain, and contracts called by name, which can't be verified.
+pub(crate) fn samples(
+    auction: SystemAuction,
+    other_auction: SystemAuction,
+) -> Vec<Sample<ExecutableDeployItem>> {
+    let spoofed_hash = ContractHash::new([1u8; 32]);
+    let spoofed_package_hash = ContractPackageHash::new([1u8; 32]);
+
+    let mut output = vec![];
+    for (entry_point, args) in sample_calls() {
+        let entry_point = entry_point.to_string();
+        let by_hash = |hash| StoredContractByHash {
+            hash,
+            entry_point: entry_point.clone(),
+            args: args.clone(),
+        };
+        let by_package_hash = |hash| StoredVersionedContractByHash {
+            hash,
+            version: None,
+            entry_point: entry_point.clone(),
+            args: args.clone(),
+        };
+        let calls = vec![
+            ("system_auction_by_hash", by_hash(auction.contract_hash)),
+            (
+                "system_auction_by_package_hash",
+                by_package_hash(auction.package_hash),
+            ),
+            ("spoofed_by_hash", by_hash(spoofed_hash)),
+            (
+                "spoofed_by_package_hash",
+                by_package_hash(spoofed_package_hash),
+            ),
+            ("other_chain_auction", by_hash(other_auction.contract_hash)),
+            (
+                "spoofed_by_name",
+                StoredContractByName {
+                    name: "auction".to_string(),
+                    entry_point: entry_point.clone(),
+                    args: args.clone(),
+                },
+            ),
+        ];
+        for (label, item) in calls {
+            // Look-alikes are still valid generic transactions.
+            output.push(prepend_label(Sample::new(label, item, true), &entry_point));
+        }
+    }
+    output
+}
@@ -11,11 +11,10 @@
 //! | `amount` | `U512` |
 
 use crate::sample::Sample;
+use crate::Network;
 use casper_execution_engine::core::engine_state::ExecutableDeployItem;
 use casper_types::{AsymmetricType, PublicKey, RuntimeArgs, U512};
 
-use super::commons::invalid_delegation;
-
 const ENTRY_POINT_NAME: &str = "undelegate";
 
 #[derive(Clone, Debug)]
@@ -60,12 +59,16 @@ fn sample_undelegations() -> Vec<Undelegate> {
         .collect()
 }
 
-pub(crate) fn valid() -> Vec<Sample<ExecutableDeployItem>> {
+pub(crate) fn valid(network: &Network) -> Vec<Sample<ExecutableDeployItem>> {
     let delegate_rargs = sample_undelegations().into_iter().map(Into::into).collect();
 
-    super::commons::valid(ENTRY_POINT_NAME, delegate_rargs)
+    super::commons::valid(ENTRY_POINT_NAME, delegate_rargs, network)
 }
 
 pub(crate) fn invalid() -> Vec<Sample<ExecutableDeployItem>> {
-    invalid_delegation(ENTRY_POINT_NAME)
+    let delegator: PublicKey = PublicKey::ed25519_from_bytes([1u8; 32]).unwrap();
+    let validator: PublicKey = PublicKey::ed25519_from_bytes([3u8; 32]).unwrap();
+    let undelegate = Undelegate::new(delegator, validator, U512::from(100000000u32));
+
+    super::commons::invalid_call(ENTRY_POINT_NAME, undelegate.into())
 }
@@ -0,0 +1,69 @@
+//! Sample test vectors for the deploys of validators withdrawing (a part of) their bids.
+//!
+//! Method name (entrypoint):
+//! `withdraw_bid`
+//!
+//! Arguments:
+//! | name | type |
+//! |---------|---------|
+//! | `public_key` | `PublicKey` |
+//! | `amount` | `U512` |
+
+use crate::sample::Sample;
+use crate::test_data::auction::commons::{self};
+use crate::Network;
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{AsymmetricType, PublicKey, RuntimeArgs, U512};
+
+const ENTRY_POINT_NAME: &str = "withdraw_bid";
+
+#[derive(Clone, Debug)]
+struct WithdrawBid {
+    public_key: PublicKey,
+    amount: U512,
+}
+
+impl WithdrawBid {
+    fn new(public_key: PublicKey, amount: U512) -> Self {
+        WithdrawBid { public_key, amount }
+    }
+}
+
+impl From<WithdrawBid> for RuntimeArgs {
+    fn from(w: WithdrawBid) -> Self {
+        let mut ra = RuntimeArgs::new();
+        ra.insert("public_key", w.public_key).unwrap();
+        ra.insert("amount", w.amount).unwrap();
+        ra
+    }
+}
+
+// Creates vector of sample `WithdrawBid` objects.
+// Each object in the output vector will have slightly different `amount` field
+// so that we cover all edge cases of the `U512` type.
+fn sample_withdrawals() -> Vec<WithdrawBid> {
+    let amount_min = U512::from(0u8);
+    let amount_mid = U512::from(100000000);
+    let amount_max = U512::MAX;
+    let amounts = vec![amount_min, amount_mid, amount_max];
+
+    let public_key: PublicKey = PublicKey::ed25519_from_bytes([3u8; 32]).unwrap();
+
+    amounts
+        .into_iter()
+        .map(|amount| WithdrawBid::new(public_key.clone(), amount))
+        .collect()
+}
+
+pub(crate) fn valid(network: &Network) -> Vec<Sample<ExecutableDeployItem>> {
+    let withdraw_bid_rargs = sample_withdrawals().into_iter().map(Into::into).collect();
+
+    commons::valid(ENTRY_POINT_NAME, withdraw_bid_rargs, network)
+}
+
+pub(crate) fn invalid() -> Vec<Sample<ExecutableDeployItem>> {
+    let public_key: PublicKey = PublicKey::ed25519_from_bytes([3u8; 32]).unwrap();
+    let withdrawal = WithdrawBid::new(public_key, U512::from(100000000u64));
+
+    commons::invalid_call(ENTRY_POINT_NAME, withdrawal.into())
+}
@@ -0,0 +1,76 @@
+//! Sample test vectors for calls to CEP-18 fungible token contracts.
+//!
+//! Method names (entrypoints) and their arguments, besides the `amount` of type `U256`:
+//! | entrypoint | arguments of type `Key` |
+//! |---------|---------|
+//! | `transfer` | `recipient` |
+//! | `approve` | `spender` |
+//! | `transfer_from` | `owner`, `recipient` |
+//! | `increase_allowance` | `spender` |
+//! | `decrease_allowance` | `spender` |
+
+use crate::parser::TOKEN_ENTRY_POINTS;
+use crate::sample::Sample;
+use crate::test_data::commons::{
+    invalid_call, prepend_label, sample_executables, sample_keys, unexpected_arg_call,
+};
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{account::AccountHash, AsymmetricType, Key, PublicKey, RuntimeArgs, U256, U512};
+
+fn sample_amounts() -> Vec<U256> {
+    vec![U256::zero(), U256::from(1_000_000_000u64), U256::MAX]
+}
+
+pub(crate) fn valid() -> Vec<Sample<ExecutableDeployItem>> {
+    let mut output = vec![];
+
+    for (entry_point, key_args) in TOKEN_ENTRY_POINTS {
+        for (key_label, key) in sample_keys() {
+            for amount in sample_amounts() {
+                let mut ra = RuntimeArgs::new();
+                for name in key_args {
+                    ra.insert(*name, key).unwrap();
+                }
+                ra.insert("amount", amount).unwrap();
+                for sample in sample_executables(entry_point, ra, Some(key_label.to_string()), true)
+                {
+                    output.push(prepend_label(sample, entry_point));
+                }
+            }
+        }
+    }
+
+    output
+}
+
+/// Constructs transactions that are invalid calls to each of the token entry points
+/// but are valid "generic" deploys - i.e. they will still be processed by a node
+/// but will not be recognized as token calls, as the token contracts may differ from the standard.
+pub(crate) fn invalid() -> Vec<Sample<ExecutableDeployItem>> {
+    let key = Key::Account(AccountHash::new([2u8; 32]));
+    let amount = U256::from(1_000_000_000u64);
+
+    let mut output = vec![];
+    for (entry_point, key_args) in TOKEN_ENTRY_POINTS {
+        let mut required_args = RuntimeArgs::new();
+        let mut wrong_type_args = RuntimeArgs::new();
+        for name in key_args {
+            required_args.insert(*name, key).unwrap();
+            wrong_type_args
+                .insert(*name, PublicKey::ed25519_from_bytes([1u8; 32]).unwrap())
+                .unwrap();
+        }
+        required_args.insert("amount", amount).unwrap();
+        wrong_type_args
+            .insert("amount", U512::from(1_000_000_000u64))
+            .unwrap();
+
+        output.extend(invalid_call(
+            entry_point,
+            required_args.clone(),
+            wrong_type_args,
+        ));
+        output.extend(unexpected_arg_call(entry_point, required_args));
+    }
+    output
+}
@@ -0,0 +1,218 @@
+//! Sample test vectors for calls to CEP-78 NFT contracts.
+//!
+//! Method names (entrypoints) and their arguments:
+//! | entrypoint | arguments |
+//! |---------|---------|
+//! | `mint` | `token_owner: Key`, `token_meta_data: String`, optional `token_hash: String` |
+//! | `transfer` | `source_key: Key`, `target_key: Key`, token identifier |
+//! | `burn` | token identifier |
+//! | `approve` | `operator: Key`, token identifier |
+//! | `set_approval_for_all` | `operator: Key`, `approve_all: bool` |
+//!
+//! Depending on the identifier mode of the contract, the token identifier is
+//! either `token_id: u64` or `token_hash: String`.
+
+use crate::sample::Sample;
+use crate::test_data::commons::{
+    invalid_call, prepend_label, sample_executables, sample_keys, unexpected_arg_call,
+};
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::{
+    account::AccountHash, runtime_args, AsymmetricType, Key, PublicKey, RuntimeArgs, U512,
+};
+
+// Distinguishes the samples from the CEP-18 ones, as both standards have `transfer` and `approve` entrypoints.
+const LABEL: &str = "nft";
+
+const TOKEN_HASH: &str = "2f4e8b1c6d0a9e3f7b5c1d8a4e6f0b2c9d3a7e5f1b8c4d6a0e2f9b3c7d5a1e8f";
+const TOKEN_META_DATA: &str = r#"{"name":"Casper NFT","token_uri":"https://example.com/nft/1","checksum":"940bffb3f2bba35f84313aa26da09ece3ad47045c6a1292c2bbd2df4ab1a55fb"}"#;
+
+// Token identifiers of the ordinal and hash identifier modes.
+fn sample_identifiers() -> Vec<(&'static str, RuntimeArgs)> {
+    vec![
+        ("id_min", runtime_args! { "token_id" => u64::MIN }),
+        ("id_max", runtime_args! { "token_id" => u64::MAX }),
+        ("hash", runtime_args! { "token_hash" => TOKEN_HASH }),
+    ]
+}
+
+fn with_identifier(mut ra: RuntimeArgs, identifier: &RuntimeArgs) -> RuntimeArgs {
+    for named_arg in identifier.named_args() {
+        ra.insert_cl_value(named_arg.name(), named_arg.cl_value().clone());
+    }
+    ra
+}
+
+fn sample_mints() -> Vec<(String, RuntimeArgs)> {
+    let mut output = vec![];
+    for (key_label, owner) in sample_keys() {
+        let mint = runtime_args! {
+            "token_owner" => owner,
+            "token_meta_data" => TOKEN_META_DATA,
+        };
+        let mut mint_with_hash = mint.clone();
+        mint_with_hash.insert("token_hash", TOKEN_HASH).unwrap();
+        output.push((format!("owner_{}", key_label), mint));
+        output.push((format!("owner_{}__hash", key_label), mint_with_hash));
+    }
+    output
+}
+
+fn sample_transfers() -> Vec<(String, RuntimeArgs)> {
+    let source = Key::Account(AccountHash::new([1u8; 32]));
+    let mut output = vec![];
+    for (key_label, target) in sample_keys() {
+        for (identifier_label, identifier) in sample_identifiers() {
+            let transfer = runtime_args! {
+                "source_key" => source,
+                "target_key" => target,
+            };
+            output.push((
+                format!("target_{}__{}", key_label, identifier_label),
+                with_identifier(transfer, &identifier),
+            ));
+        }
+    }
+    output
+}
+
+fn sample_burns() -> Vec<(String, RuntimeArgs)> {
+    sample_identifiers()
+        .into_iter()
+        .map(|(label, identifier)| (label.to_string(), identifier))
+        .collect()
+}
+
+fn sample_approvals() -> Vec<(String, RuntimeArgs)> {
+    let mut output = vec![];
+    for (key_label, operator) in sample_keys() {
+        for (identifier_label, identifier) in sample_identifiers() {
+            let approve = runtime_args! { "operator" => operator };
+            output.push((
+                format!("operator_{}__{}", key_label, identifier_label),
+                with_identifier(approve, &identifier),
+            ));
+        }
+    }
+    output
+}
+
+fn sample_approvals_for_all() -> Vec<(String, RuntimeArgs)> {
+    let mut output = vec![];
+    for (key_label, operator) in sample_keys() {
+        for approve_all in [true, false] {
+            let approve = runtime_args! {
+                "operator" => operator,
+                "approve_all" => approve_all,
+            };
+            output.push((format!("operator_{}__{}", key_label, approve_all), approve));
+        }
+    }
+    output
+}
+
+pub(crate) fn valid() -> Vec<Sample<ExecutableDeployItem>> {
+    let entry_points = vec![
+        ("mint", sample_mints()),
+        ("transfer", sample_transfers()),
+        ("burn", sample_burns()),
+        ("approve", sample_approvals()),
+        ("set_approval_for_all", sample_approvals_for_all()),
+    ];
+
+    let mut output = vec![];
+    for (entry_point, rargs) in entry_points {
+        for (label, ra) in rargs {
+            for sample in sample_executables(entry_point, ra, Some(label), true) {
+                output.push(prepend_label(
+                    sample,
+                    &format!("{}__{}", LABEL, entry_point),
+                ));
+            }
+        }
+    }
+    output
+}
+
+/// Constructs transactions that are invalid calls to each of the NFT entry points
+/// but are valid "generic" deploys - i.e. they will still be processed by a node
+/// but will not be recognized as NFT calls, as the NFT contracts may differ from the standard.
+pub(crate) fn invalid() -> Vec<Sample<ExecutableDeployItem>> {
+    let source = Key::Account(AccountHash::new([1u8; 32]));
+    let target = Key::Account(AccountHash::new([2u8; 32]));
+    let public_key = PublicKey::ed25519_from_bytes([1u8; 32]).unwrap();
+
+    let entry_points = vec![
+        (
+            "mint",
+            runtime_args! {
+                "token_owner" => target,
+                "token_meta_data" => TOKEN_META_DATA,
+            },
+        ),
+        (
+            "transfer",
+            runtime_args! {
+                "source_key" => source,
+                "target_key" => target,
+                "token_id" => 1u64,
+            },
+        ),
+        ("burn", runtime_args! { "token_id" => 1u64 }),
+        (
+            "approve",
+            runtime_args! {
+                "operator" => target,
+                "token_id" => 1u64,
+            },
+        ),
+        (
+            "set_approval_for_all",
+            runtime_args! {
+                "operator" => target,
+                "approve_all" => true,
+            },
+        ),
+    ];
+    // Each entry point takes only some of them.
+    let wrong_type_args = runtime_args! {
+        "token_owner" => public_key.clone(),
+        "source_key" => public_key.clone(),
+        "target_key" => public_key.clone(),
+        "operator" => public_key,
+        "token_id" => U512::one(),
+        "approve_all" => "true",
+    };
+
+    let mut output = vec![];
+    for (entry_point, required_args) in entry_points {
+        output.extend(invalid_call(
+            entry_point,
+            required_args.clone(),
+            wrong_type_args.clone(),
+        ));
+        output.extend(unexpected_arg_call(entry_point, required_args));
+    }
+
+    let both_identifiers = runtime_args! {
+        "source_key" => source,
+        "target_key" => target,
+        "token_id" => 1u64,
+        "token_hash" => TOKEN_HASH,
+    };
+    output.extend(
+        sample_executables(
+            "transfer",
+            both_identifiers,
+            Some("both_identifiers".to_string()),
+            true,
+        )
+        .into_iter()
+        .map(|sample| prepend_label(sample, "transfer")),
+    );
+
+    output
+        .into_iter()
+        .map(|sample| prepend_label(sample, LABEL))
+        .collect()
+}
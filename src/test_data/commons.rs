@@ -3,21 +3,23 @@ use casper_execution_engine::core::engine_state::executable_deploy_item::Executa
     StoredVersionedContractByName,
 };
 use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::account::AccountHash;
 use casper_types::bytesrepr::Bytes;
 use casper_types::{
-    ContractHash, ContractPackageHash, ContractVersion, RuntimeArgs, UREF_ADDR_LENGTH,
+    ContractHash, ContractPackageHash, ContractVersion, Key, RuntimeArgs, UREF_ADDR_LENGTH,
 };
 
-use crate::sample::Sample;
+use crate::sample::{Sample, Validity};
 
 // Using provided `entry_point` and arguments, returns a vector of samples
 // for each of the existing `ExecutableDeployItem` variant.
-pub(crate) fn sample_executables(
+pub(crate) fn sample_executables<V: Into<Validity>>(
     entry_point: &str,
     ra: RuntimeArgs,
     base_label: Option<String>,
-    valid: bool,
+    valid: V,
 ) -> Vec<Sample<ExecutableDeployItem>> {
+    let valid = valid.into();
     let contract_hash = ContractHash::new([1u8; 32]);
     let contract_package_hash = ContractPackageHash::new([1u8; 32]);
     let contract_version: ContractVersion = 1;
@@ -96,6 +98,88 @@ pub(crate) fn prepend_label(
     Sample::new(label, executable, valid)
 }
 
+/// Constructs calls that are invalid `entry_point` calls but valid "generic" deploys - i.e. they
+/// will still be processed by a node but will not be recognized as calls to the entry point.
+///
+/// Each of them misses one of the `required_args` or has one of them replaced with its value
+/// in `wrong_type_args` (arguments that aren't required are ignored). The last ones have all
+/// the `required_args` but call an invalid entrypoint.
+pub(crate) fn invalid_call(
+    entry_point: &str,
+    required_args: RuntimeArgs,
+    wrong_type_args: RuntimeArgs,
+) -> Vec<Sample<ExecutableDeployItem>> {
+    let without_arg = |skipped: &str| {
+        let mut ra = RuntimeArgs::new();
+        for named_arg in required_args.named_args() {
+            if named_arg.name() != skipped {
+                ra.insert_cl_value(named_arg.name(), named_arg.cl_value().clone());
+            }
+        }
+        ra
+    };
+
+    // We're setting the "validity bit" to `true`, otherwise such transaction would
+    // be rejected by the Ledger Hardware and we don't want that. dApps could be written
+    // in such a way that they use similar arguments.
+    let missing_args = required_args.named_args().map(|named_arg| {
+        let label = format!("missing_{}", named_arg.name());
+        Sample::new(label, without_arg(named_arg.name()), true)
+    });
+    let invalid_type_args = wrong_type_args
+        .named_args()
+        .filter(|named_arg| required_args.get(named_arg.name()).is_some())
+        .map(|named_arg| {
+            let mut ra = without_arg(named_arg.name());
+            ra.insert_cl_value(named_arg.name(), named_arg.cl_value().clone());
+            let label = format!("invalid_type_{}", named_arg.name());
+            Sample::new(label, ra, true)
+        });
+    let invalid_args: Vec<Sample<RuntimeArgs>> = missing_args.chain(invalid_type_args).collect();
+
+    invalid_args
+        .into_iter()
+        .flat_map(|sample_ra| {
+            let (label, ra, valid) = sample_ra.destructure();
+            sample_executables(entry_point, ra, Some(label), valid)
+        })
+        .chain(sample_executables(
+            "invalid",
+            required_args.clone(),
+            Some("invalid_entrypoint".to_string()),
+            true, // Even though entrypoint is invalid, it's possible that generic transaction uses similar set of arguments but changes the entrypoint. In that case, transaction MUSTN'T be invalid b/c it will get rejected by the Ledger.
+        ))
+        .map(|sample| prepend_label(sample, entry_point))
+        .collect()
+}
+
+/// Constructs calls to the `entry_point` of a contract standard with an argument on top of the
+/// `required_args`. They are valid "generic" deploys, as the standards are recognised only by
+/// their exact arguments - otherwise the additional one wouldn't be displayed.
+pub(crate) fn unexpected_arg_call(
+    entry_point: &str,
+    mut required_args: RuntimeArgs,
+) -> Vec<Sample<ExecutableDeployItem>> {
+    required_args.insert("memo", "sample-memo").unwrap();
+    sample_executables(
+        entry_point,
+        required_args,
+        Some("unexpected_arg".to_string()),
+        true,
+    )
+    .into_iter()
+    .map(|sample| prepend_label(sample, entry_point))
+    .collect()
+}
+
+// Keys of both an account and a contract, as token holders can be either.
+pub(crate) fn sample_keys() -> Vec<(&'static str, Key)> {
+    vec![
+        ("account", Key::Account(AccountHash::new([2u8; 32]))),
+        ("hash", Key::Hash([4u8; 32])),
+    ]
+}
+
 pub(crate) const UREF_ADDR: [u8; UREF_ADDR_LENGTH] = [
     74, 207, 207, 108, 104, 76, 88, 202, 246, 179, 41, 110, 58, 151, 196, 160, 74, 250, 247, 123,
     184, 117, 202, 154, 64, 164, 93, 178, 84, 233, 74, 117,
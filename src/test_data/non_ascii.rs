@@ -0,0 +1,38 @@
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_types::RuntimeArgs;
+
+use crate::sample::Sample;
+
+use super::commons::{prepend_label, sample_executables};
+
+// Entry points (and so contract names) with characters that Ledger's font can't display,
+// paired with the labels of their samples.
+const ENTRY_POINTS: [(&str, &str); 10] = [
+    ("latin_accent", "café"),
+    ("cjk", "合約"),
+    ("emoji", "launch_🚀"),
+    ("control_tab", "tab\there"),
+    ("control_newline", "new\nline"),
+    ("control_bell", "bell\u{7}"),
+    ("zero_width_space", "zero\u{200b}width"),
+    ("zero_width_joiner", "zero\u{200d}joiner"),
+    ("bidi_override", "rtl\u{202e}override"),
+    ("backslash", "back\\slash"),
+];
+
+/// Returns samples calling contracts by names with non-printable ASCII characters.
+/// Such deploys are valid, Ledger displays the names with the characters escaped.
+pub(crate) fn valid() -> Vec<Sample<ExecutableDeployItem>> {
+    ENTRY_POINTS
+        .iter()
+        .flat_map(|(label, entry_point)| {
+            sample_executables(
+                entry_point,
+                RuntimeArgs::new(),
+                Some(label.to_string()),
+                true,
+            )
+        })
+        .map(|sample| prepend_label(sample, "non_ascii"))
+        .collect()
+}
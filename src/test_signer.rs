@@ -0,0 +1,101 @@
+use bip32::{DerivationPath, XPrv};
+use casper_node::types::DeployHash;
+use casper_types::{bytesrepr::ToBytes, crypto, PublicKey, SecretKey, Signature};
+use hmac::Hmac;
+use sha2::Sha512;
+
+/// Mnemonic of the seed that Zemu and Speculos test devices are set up with.
+pub const TEST_MNEMONIC: &str =
+    "equip will roof matter pink blind book anxiety banner elbow sun young";
+
+/// Derivation path of the key the Casper app signs with, the same as in [`crate::apdu::DERIVATION_PATH`].
+pub const DERIVATION_PATH: &str = "m/44'/506'/0'/0/0";
+
+// Number of PBKDF2 rounds deriving the seed from the mnemonic, from BIP-39.
+const SEED_ROUNDS: u32 = 2048;
+
+/// The secp256k1 key that a test device signs the transactions with.
+///
+/// Derived from [`TEST_MNEMONIC`] at [`DERIVATION_PATH`], without access to any device.
+pub struct TestSigner {
+    secret_key: SecretKey,
+    public_key: PublicKey,
+}
+
+impl TestSigner {
+    pub fn new() -> Self {
+        // BIP-39 seed with an empty passphrase. `bip32`'s mnemonic supports 24-word phrases only,
+        // while the test devices' one has 12 words.
+        let mut seed = [0u8; 64];
+        pbkdf2::pbkdf2::<Hmac<Sha512>>(
+            TEST_MNEMONIC.as_bytes(),
+            b"mnemonic",
+            SEED_ROUNDS,
+            &mut seed,
+        );
+        let path: DerivationPath = DERIVATION_PATH.parse().expect("valid derivation path");
+        let xprv = XPrv::derive_from_path(&seed, &path).expect("key derivation");
+        let secret_key = SecretKey::secp256k1_from_bytes(xprv.private_key().to_bytes())
+            .expect("valid secp256k1 key");
+        let public_key = PublicKey::from(&secret_key);
+        TestSigner {
+            secret_key,
+            public_key,
+        }
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    /// Signs the deploy's hash. Signatures are deterministic (RFC 6979), like the device's.
+    pub fn sign(&self, deploy_hash: &DeployHash) -> Signature {
+        crypto::sign(deploy_hash.inner(), &self.secret_key, &self.public_key)
+    }
+
+    /// Hex-encoded signature of the deploy's hash, as returned by the device: 64 bytes of `r` and `s`,
+    /// without the algorithm tag of Casper's serialization.
+    pub fn sign_to_hex(&self, deploy_hash: &DeployHash) -> String {
+        let bytes = self
+            .sign(deploy_hash)
+            .to_bytes()
+            .expect("signature to serialize");
+        hex::encode(&bytes[1..])
+    }
+}
+
+impl Default for TestSigner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_node::types::DeployHash;
+    use casper_types::{bytesrepr::ToBytes, crypto};
+
+    use super::TestSigner;
+
+    // Account of the test devices' key: the secp256k1 tag followed by the compressed public key.
+    const TEST_ACCOUNT: &str =
+        "02028b2ddbe59976ad2f4138ca46553866de5124d13db4e13611ca751eedde9e0297";
+
+    #[test]
+    fn deterministic_secp256k1_signatures() {
+        let signer = TestSigner::new();
+        assert_eq!(
+            TEST_ACCOUNT,
+            hex::encode(signer.public_key().to_bytes().unwrap())
+        );
+
+        let deploy_hash = DeployHash::new([7; 32].into());
+        let signature = signer.sign(&deploy_hash);
+        assert!(crypto::verify(deploy_hash.inner(), &signature, signer.public_key()).is_ok());
+        assert_eq!(
+            "064a75c7c7fe0bd337e9dab16ebc85fff191a6eba220cda24eb87ed051d78f16\
+             723609abd38ba06f9ed3e5559941604a873d4d6e3d350aa8629323ad1ad30c13",
+            signer.sign_to_hex(&deploy_hash)
+        );
+    }
+}
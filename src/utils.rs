@@ -3,7 +3,7 @@ use casper_types::{
 };
 use itertools::Itertools;
 
-use crate::checksummed_hex;
+use crate::{checksummed_hex, error::Error};
 
 /// Turn JSON representation into a string.
 fn serde_value_to_str(value: &serde_json::Value) -> String {
@@ -57,14 +57,12 @@ fn drop_key_type_prefix(cl_in: String) -> String {
 /// Extracts the `parsed` field from the `CLValue`
 /// (which is a pair of type identifier and raw bytes).
 /// It should be human-readable.
-pub(crate) fn cl_value_to_string(cl_in: &CLValue) -> String {
+pub(crate) fn cl_value_to_string(cl_in: &CLValue) -> Result<String, Error> {
     match cl_in.cl_type() {
         CLType::Key => {
-            let account: Key = FromBytes::from_bytes(cl_in.inner_bytes())
-                .expect("key account to be deserialized with FromBytes")
-                .0;
+            let account: Key = from_inner_bytes(cl_in)?;
 
-            match account {
+            let value = match account {
                 Key::URef(uref) => checksummed_hex::encode(uref.addr()),
                 Key::Hash(addr) => checksummed_hex::encode(addr),
                 Key::Transfer(addr) => checksummed_hex::encode(addr.value()),
@@ -75,52 +73,59 @@ pub(crate) fn cl_value_to_string(cl_in: &CLValue) -> String {
                 | Key::Unbond(account_hash)
                 | Key::Withdraw(account_hash)
                 | Key::Bid(account_hash) => checksummed_hex::encode(&account_hash),
-                _ => parse_as_default_json(cl_in),
-            }
+                _ => parse_as_default_json(cl_in)?,
+            };
+            Ok(value)
         }
         CLType::URef => {
-            let uref: URef = FromBytes::from_bytes(cl_in.inner_bytes())
-                .expect("uref to be deserialized with FromBytes")
-                .0;
-            checksummed_hex::encode(uref.addr())
+            let uref: URef = from_inner_bytes(cl_in)?;
+            Ok(checksummed_hex::encode(uref.addr()))
         }
         CLType::PublicKey => {
-            let public_key: PublicKey = FromBytes::from_bytes(cl_in.inner_bytes())
-                .expect("public key to be deserialized with FromBytes")
-                .0;
+            let public_key: PublicKey = from_inner_bytes(cl_in)?;
             parse_public_key(&public_key)
         }
         CLType::ByteArray(length) => {
-            let (bytes, _remainder) = cl_in.inner_bytes().split_at(*length as usize);
+            let bytes = cl_in.inner_bytes().get(..*length as usize).ok_or_else(|| {
+                Error::InvalidCLValue {
+                    cl_type: cl_in.cl_type().clone(),
+                    reason: format!("expected {} bytes", length),
+                }
+            })?;
 
-            checksummed_hex::encode(&bytes)
+            Ok(checksummed_hex::encode(&bytes))
         }
         _ => parse_as_default_json(&cl_in),
     }
 }
 
-fn parse_as_default_json(input: &CLValue) -> String {
-    match serde_json::to_value(&input) {
-        Ok(value) => {
-            let parsed = value.get("parsed").unwrap();
-            serde_value_to_str(parsed)
-        }
-        Err(err) => {
-            eprintln!("error when parsing CLValue to CLValueJson#Object, {}", err);
-            panic!("{:?}", err)
-        }
-    }
+// Deserializes the raw bytes of the `CLValue` into its declared type.
+fn from_inner_bytes<T: FromBytes>(cl_in: &CLValue) -> Result<T, Error> {
+    let (value, _remainder) =
+        FromBytes::from_bytes(cl_in.inner_bytes()).map_err(|err| Error::InvalidCLValue {
+            cl_type: cl_in.cl_type().clone(),
+            reason: err.to_string(),
+        })?;
+    Ok(value)
+}
+
+fn parse_as_default_json(input: &CLValue) -> Result<String, Error> {
+    let value = serde_json::to_value(&input).map_err(|err| Error::CLValueJson(err.to_string()))?;
+    let parsed = value
+        .get("parsed")
+        .ok_or_else(|| Error::CLValueJson("missing `parsed` field".to_string()))?;
+    Ok(serde_value_to_str(parsed))
 }
 
 // `PublicKey`'s `String` representation includes a `PublicKey::<variant>` prefix.
 // This method drops that prefix (and the closing ')') from the `String` representation for the Ledger.
-pub(crate) fn parse_public_key(key: &PublicKey) -> String {
+pub(crate) fn parse_public_key(key: &PublicKey) -> Result<String, Error> {
     let key_tag = match key {
-        PublicKey::System => panic!("Did not expect system key"),
+        PublicKey::System => return Err(Error::SystemPublicKey),
         PublicKey::Ed25519(_) => format!("0{}", ED25519_TAG),
         PublicKey::Secp256k1(_) => format!("0{}", SECP256K1_TAG),
     };
 
     let checksummed_key = checksummed_hex::encode(Into::<Vec<u8>>::into(key));
-    format!("{}{}", key_tag, checksummed_key)
+    Ok(format!("{}{}", key_tag, checksummed_key))
 }
@@ -0,0 +1,377 @@
+//! Loading test vectors files back, e.g. to compare them with freshly generated ones or to check
+//! the files of other generators.
+
+use std::{collections::HashSet, fmt::Display, fs, io, path::Path};
+
+use crate::{
+    checksummed_hex,
+    ledger::{self, ZondaxRepr},
+};
+
+// Label of the page displaying the deploy's hash.
+const TXN_HASH_LABEL: &str = "Txn hash";
+
+/// How the indices of the test vectors are checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexCheck {
+    /// Every index follows the index of the previous test vector, so none of them is missing.
+    Contiguous,
+    /// Indices are strictly increasing but can have gaps, like in a file of filtered test vectors,
+    /// which keep their indices in the full set.
+    AllowGaps,
+}
+
+/// Inconsistency found in a test vectors file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Issue {
+    /// Index doesn't follow the index of the previous test vector.
+    NonContiguousIndex { expected: usize, found: usize },
+    /// Index isn't greater than the index of the previous test vector, when gaps are allowed.
+    UnorderedIndex { previous: usize, found: usize },
+    /// More than one test vector has the name.
+    DuplicateName(String),
+    /// `blob` doesn't decode to a deploy.
+    InvalidBlob { index: usize, reason: String },
+    /// `Txn hash` page doesn't display the hash of the deploy in `blob`.
+    HashMismatch {
+        index: usize,
+        expected: String,
+        displayed: String,
+    },
+    /// Page of `output` or `output_expert` that is malformed or out of order.
+    InvalidPage {
+        index: usize,
+        page: String,
+        reason: String,
+    },
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Issue::NonContiguousIndex { expected, found } => {
+                write!(f, "expected index {}, found {}", expected, found)
+            }
+            Issue::UnorderedIndex { previous, found } => {
+                write!(f, "index {} follows index {}", found, previous)
+            }
+            Issue::DuplicateName(name) => write!(f, "duplicate name '{}'", name),
+            Issue::InvalidBlob { index, reason } => write!(f, "{}: {}", index, reason),
+            Issue::HashMismatch {
+                index,
+                expected,
+                displayed,
+            } => write!(
+                f,
+                "{}: Txn hash page displays '{}' instead of '{}'",
+                index, displayed, expected
+            ),
+            Issue::InvalidPage {
+                index,
+                page,
+                reason,
+            } => write!(f, "{}: page '{}' {}", index, page, reason),
+        }
+    }
+}
+
+/// Reasons why a test vectors file can't be loaded.
+#[derive(Debug)]
+pub enum LoadError {
+    Io(io::Error),
+    /// The file doesn't match the schema of [`ZondaxRepr`], which rejects unknown fields.
+    Json(serde_json::Error),
+    /// The test vectors are inconsistent.
+    Invalid(Vec<Issue>),
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadError::Io(error) => write!(f, "can't read test vectors: {}", error),
+            LoadError::Json(error) => write!(f, "can't parse test vectors: {}", error),
+            LoadError::Invalid(issues) => {
+                write!(f, "{} issues found in test vectors:", issues.len())?;
+                for issue in issues {
+                    write!(f, "\n  {}", issue)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+/// Reads and validates (see [`validate`]) the test vectors file.
+pub fn load<P: AsRef<Path>>(path: P, indices: IndexCheck) -> Result<Vec<ZondaxRepr>, LoadError> {
+    let json = fs::read_to_string(path).map_err(LoadError::Io)?;
+    from_json(&json, indices)
+}
+
+/// Parses and validates (see [`validate`]) the test vectors.
+pub fn from_json(json: &str, indices: IndexCheck) -> Result<Vec<ZondaxRepr>, LoadError> {
+    let vectors: Vec<ZondaxRepr> = serde_json::from_str(json).map_err(LoadError::Json)?;
+    let issues = validate(&vectors, indices);
+    if !issues.is_empty() {
+        return Err(LoadError::Invalid(issues));
+    }
+    Ok(vectors)
+}
+
+/// Checks that:
+/// * indices of the test vectors are contiguous (they don't have to start at 0) or, with
+///   [`IndexCheck::AllowGaps`], strictly increasing,
+/// * names are unique,
+/// * every `blob` decodes to a deploy, whose hash is displayed on the `Txn hash` page,
+/// * pages of `output` and `output_expert` are numbered consistently: elements are numbered from 0,
+///   and the pages of an element spanning `m` pages are labeled from `[1/m]` to `[m/m]`.
+///
+/// Returns all the issues found.
+pub fn validate(vectors: &[ZondaxRepr], indices: IndexCheck) -> Vec<Issue> {
+    let mut issues = vec![];
+    let mut names = HashSet::new();
+    for (position, vector) in vectors.iter().enumerate() {
+        if let Some(previous) = position.checked_sub(1).map(|previous| &vectors[previous]) {
+            match indices {
+                IndexCheck::Contiguous if vector.index != previous.index + 1 => {
+                    issues.push(Issue::NonContiguousIndex {
+                        expected: previous.index + 1,
+                        found: vector.index,
+                    })
+                }
+                IndexCheck::AllowGaps if vector.index <= previous.index => {
+                    issues.push(Issue::UnorderedIndex {
+                        previous: previous.index,
+                        found: vector.index,
+                    })
+                }
+                IndexCheck::Contiguous | IndexCheck::AllowGaps => {}
+            }
+        }
+        if !names.insert(vector.name.as_str()) {
+            issues.push(Issue::DuplicateName(vector.name.clone()));
+        }
+        issues.extend(validate_vector(vector));
+    }
+    issues
+}
+
+fn validate_vector(vector: &ZondaxRepr) -> Vec<Issue> {
+    let mut issues = vec![];
+    let deploy = match ledger::deploy_from_blob(&vector.blob) {
+        Ok(deploy) => Some(deploy),
+        Err(error) => {
+            issues.push(Issue::InvalidBlob {
+                index: vector.index,
+                reason: error.to_string(),
+            });
+            None
+        }
+    };
+    for output in [&vector.output, &vector.output_expert] {
+        let pages = match parse_pages(output) {
+            Ok(pages) => pages,
+            Err((page, reason)) => {
+                issues.push(Issue::InvalidPage {
+                    index: vector.index,
+                    page: page.to_string(),
+                    reason,
+                });
+                continue;
+            }
+        };
+        // Vectors of the deploys that Ledger can't display have no pages.
+        if let (Some(deploy), false) = (&deploy, pages.is_empty()) {
+            let expected = checksummed_hex::encode(deploy.id().inner());
+            let displayed: String = pages
+                .iter()
+                .filter(|page| page.label == TXN_HASH_LABEL)
+                .map(|page| page.value)
+                .collect();
+            if displayed != expected {
+                issues.push(Issue::HashMismatch {
+                    index: vector.index,
+                    expected,
+                    displayed,
+                });
+            }
+        }
+    }
+    issues
+}
+
+/// Single page of the output, e.g. `1 | Txn hash [2/4] : 1a2b...`.
+struct Page<'a> {
+    element: usize,
+    label: &'a str,
+    // Number of the page and the number of pages of the element, when it spans multiple pages.
+    part: Option<(usize, usize)>,
+    value: &'a str,
+}
+
+impl<'a> Page<'a> {
+    fn parse(page: &'a str) -> Result<Self, String> {
+        let (element, rest) = page
+            .split_once(" | ")
+            .ok_or_else(|| "has no element index".to_string())?;
+        let element = element
+            .parse()
+            .map_err(|_| format!("has invalid element index '{}'", element))?;
+        let (title, value) = rest
+            .split_once(" : ")
+            .ok_or_else(|| "has no label".to_string())?;
+        let (label, part) = match title.strip_suffix(']').and_then(|t| t.rsplit_once(" [")) {
+            Some((label, part)) => (label, Some(Self::parse_part(part)?)),
+            None => (title, None),
+        };
+        Ok(Page {
+            element,
+            label,
+            part,
+            value,
+        })
+    }
+
+    // Parses `i/m`.
+    fn parse_part(part: &str) -> Result<(usize, usize), String> {
+        let invalid = || format!("has invalid page number '[{}]'", part);
+        let (number, count) = part.split_once('/').ok_or_else(invalid)?;
+        Ok((
+            number.parse().map_err(|_| invalid())?,
+            count.parse().map_err(|_| invalid())?,
+        ))
+    }
+
+    // Whether it's the last page of its element.
+    fn is_last(&self) -> bool {
+        self.part.map_or(true, |(number, count)| number == count)
+    }
+}
+
+// Parses the pages and checks their numbering. Fails with the first inconsistent page.
+fn parse_pages(output: &[String]) -> Result<Vec<Page<'_>>, (&str, String)> {
+    let mut pages: Vec<Page> = vec![];
+    for line in output {
+        let page = Page::parse(line).map_err(|reason| (line.as_str(), reason))?;
+        let expected_part = match pages.last() {
+            // Next page of the same element.
+            Some(previous) if !previous.is_last() => {
+                if page.element != previous.element || page.label != previous.label {
+                    return Err((line, "interrupts the previous element".to_string()));
+                }
+                previous.part.map(|(number, count)| (number + 1, count))
+            }
+            previous => {
+                let expected_element = previous.map_or(0, |previous| previous.element + 1);
+                if page.element != expected_element {
+                    return Err((line, format!("should be element {}", expected_element)));
+                }
+                match page.part {
+                    Some((_, count)) if count > 1 => Some((1, count)),
+                    _ => None,
+                }
+            }
+        };
+        if page.part != expected_part {
+            let expected = expected_part.map_or("no page number".to_string(), |(i, m)| {
+                format!("page number [{}/{}]", i, m)
+            });
+            return Err((line, format!("should have {}", expected)));
+        }
+        pages.push(page);
+    }
+    match pages.last() {
+        Some(last) if !last.is_last() => Err((
+            output.last().unwrap(),
+            "is not the last page of its element".to_string(),
+        )),
+        _ => Ok(pages),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use glob::Pattern;
+
+    use super::{load, validate, IndexCheck, Issue, LoadError};
+    use crate::{
+        ledger::{deploys_to_json, LimitedLedgerConfig},
+        test_data::{delegate_samples, SampleFamily, SampleFilter},
+        Network, TestRng,
+    };
+
+    #[test]
+    fn finds_inconsistencies() {
+        let mut rng = TestRng::new();
+        let samples = delegate_samples(&mut rng, &Network::default())
+            .into_iter()
+            .take(3)
+            .enumerate();
+        let mut vectors = deploys_to_json(samples, &LimitedLedgerConfig::new(15)).unwrap();
+        assert_eq!(
+            Vec::<Issue>::new(),
+            validate(&vectors, IndexCheck::Contiguous)
+        );
+
+        vectors[1].name = vectors[0].name.clone();
+        vectors[2].index = 3;
+        vectors[2].output.swap(0, 1);
+        let issues = validate(&vectors, IndexCheck::Contiguous);
+        assert_eq!(3, issues.len());
+        assert_eq!(Issue::DuplicateName(vectors[0].name.clone()), issues[0]);
+        assert_eq!(
+            Issue::NonContiguousIndex {
+                expected: 2,
+                found: 3
+            },
+            issues[1]
+        );
+        assert!(matches!(issues[2], Issue::InvalidPage { index: 3, .. }));
+
+        // A gap is allowed on request, but the indices still have to increase.
+        assert_eq!(2, validate(&vectors, IndexCheck::AllowGaps).len());
+        vectors[2].index = 1;
+        assert_eq!(
+            Some(&Issue::UnorderedIndex {
+                previous: 1,
+                found: 1
+            }),
+            validate(&vectors, IndexCheck::AllowGaps).get(1)
+        );
+    }
+
+    #[test]
+    fn loads_filtered_vectors_only_with_gaps_allowed() {
+        let mut rng = TestRng::new();
+        let filter = SampleFilter {
+            families: vec![SampleFamily::Delegate, SampleFamily::Generic],
+            label: Some(Pattern::new("*__payment_system*").unwrap()),
+            valid: None,
+        };
+        let samples = filter
+            .samples(&mut rng, &[Network::Mainnet, Network::Testnet])
+            .into_iter()
+            .map(|(index, _, sample)| (index, sample));
+        let vectors = deploys_to_json(samples, &LimitedLedgerConfig::new(15)).unwrap();
+        // Indices of the filtered vectors have gaps.
+        assert!(vectors
+            .windows(2)
+            .any(|pair| pair[1].index > pair[0].index + 1));
+
+        let path = env::temp_dir().join("casper_deploy_generator_filtered_vectors.json");
+        fs::write(&path, serde_json::to_string_pretty(&vectors).unwrap()).unwrap();
+        let contiguous = load(&path, IndexCheck::Contiguous);
+        let with_gaps = load(&path, IndexCheck::AllowGaps);
+        fs::remove_file(&path).unwrap();
+        assert!(matches!(
+            contiguous,
+            Err(LoadError::Invalid(issues)) if issues
+                .iter()
+                .all(|issue| matches!(issue, Issue::NonContiguousIndex { .. }))
+        ));
+        assert_eq!(vectors, with_gaps.unwrap());
+    }
+}